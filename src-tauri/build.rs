@@ -1,3 +1,75 @@
+use serde_json::Value;
+use std::{env, fs, path::Path};
+
+const LOCALES_DIR: &str = "../src/locales";
+
+// Keys match `Label::key` in src/core/tray.rs
+const TRAY_LABELS: [(&str, &str); 15] = [
+    ("preference", "composables.useAppMenu.labels.preference"),
+    ("showCat", "composables.useAppMenu.labels.showCat"),
+    ("hideCat", "composables.useAppMenu.labels.hideCat"),
+    ("passThrough", "composables.useAppMenu.labels.passThrough"),
+    ("alwaysOnTop", "composables.useTray.alwaysOnTop"),
+    ("windowSize", "composables.useAppMenu.labels.windowSize"),
+    ("opacity", "composables.useAppMenu.labels.opacity"),
+    ("model", "composables.useCatWindows.labels.model"),
+    ("checkUpdate", "composables.useTray.checkUpdate"),
+    ("openSource", "composables.useTray.openSource"),
+    ("restartApp", "composables.useAppMenu.labels.restartApp"),
+    ("quitApp", "composables.useAppMenu.labels.quitApp"),
+    ("standard", "composables.useCatWindows.modes.standard"),
+    ("keyboard", "composables.useCatWindows.modes.keyboard"),
+    ("gamepad", "composables.useCatWindows.modes.gamepad"),
+];
+
+fn generate_tray_texts() {
+    println!("cargo:rerun-if-changed={}", LOCALES_DIR);
+
+    let mut entries = fs::read_dir(LOCALES_DIR)
+        .expect("failed to read the locales directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+
+    entries.sort();
+
+    let mut arms = String::new();
+
+    for path in entries {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let language = path.file_stem().unwrap().to_string_lossy().to_string();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let locale: Value = serde_json::from_str(&content)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {}", path.display(), err));
+
+        for (key, pointer) in TRAY_LABELS {
+            let pointer = format!("/{}", pointer.replace('.', "/"));
+
+            let Some(text) = locale.pointer(&pointer).and_then(Value::as_str) else {
+                continue;
+            };
+
+            arms.push_str(&format!(
+                "        ({:?}, {:?}) => Some({:?}),\n",
+                language, key, text
+            ));
+        }
+    }
+
+    let code = format!(
+        "fn bundled_text(language: &str, key: &str) -> Option<&'static str> {{\n    match (language, key) {{\n{}        _ => None,\n    }}\n}}\n",
+        arms
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("tray_texts.rs");
+
+    fs::write(out_path, code).unwrap();
+}
+
 fn main() {
+    generate_tray_texts();
+
     tauri_build::build()
 }
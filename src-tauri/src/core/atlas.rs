@@ -0,0 +1,222 @@
+use image::{
+    RgbaImage,
+    imageops::{crop_imm, replace},
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs::{read_dir, write},
+    path::{Path, PathBuf},
+};
+use tauri::{async_runtime::spawn_blocking, command};
+
+const KEY_GROUPS: &[&str] = &["left-keys", "right-keys"];
+const PADDING: u32 = 2;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtlasFrame {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    offset_x: u32,
+    offset_y: u32,
+    source_width: u32,
+    source_height: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AtlasIndex {
+    image: String,
+    width: u32,
+    height: u32,
+    frames: BTreeMap<String, AtlasFrame>,
+}
+
+struct Sprite {
+    name: String,
+    image: RgbaImage,
+    offset_x: u32,
+    offset_y: u32,
+    source_width: u32,
+    source_height: u32,
+}
+
+fn trim_transparent(image: &RgbaImage) -> Option<(u32, u32, u32, u32)> {
+    let mut bounds: Option<(u32, u32, u32, u32)> = None;
+
+    for (x, y, pixel) in image.enumerate_pixels() {
+        if pixel[3] == 0 {
+            continue;
+        }
+
+        bounds = Some(match bounds {
+            Some((min_x, min_y, max_x, max_y)) => {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            }
+            None => (x, y, x, y),
+        });
+    }
+
+    bounds.map(|(min_x, min_y, max_x, max_y)| (min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+}
+
+fn load_sprites(group_path: &Path) -> Vec<Sprite> {
+    let Ok(entries) = read_dir(group_path) else {
+        return vec![];
+    };
+
+    let mut sprites = vec![];
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.extension().is_none_or(|ext| ext != "png") {
+            continue;
+        }
+
+        let Ok(image) = image::open(&path) else {
+            continue;
+        };
+
+        let image = image.to_rgba8();
+        let (source_width, source_height) = image.dimensions();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        let sprite = match trim_transparent(&image) {
+            Some((x, y, width, height)) => Sprite {
+                name,
+                image: crop_imm(&image, x, y, width, height).to_image(),
+                offset_x: x,
+                offset_y: y,
+                source_width,
+                source_height,
+            },
+            None => Sprite {
+                name,
+                image: RgbaImage::new(0, 0),
+                offset_x: 0,
+                offset_y: 0,
+                source_width,
+                source_height,
+            },
+        };
+
+        sprites.push(sprite);
+    }
+
+    sprites
+}
+
+fn pack_sprites(mut sprites: Vec<Sprite>, image_name: String) -> (RgbaImage, AtlasIndex) {
+    sprites.sort_by(|a, b| {
+        b.image
+            .height()
+            .cmp(&a.image.height())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let total_area: u64 = sprites
+        .iter()
+        .map(|sprite| {
+            (sprite.image.width() + PADDING) as u64 * (sprite.image.height() + PADDING) as u64
+        })
+        .sum();
+
+    let max_width = sprites
+        .iter()
+        .map(|sprite| sprite.image.width())
+        .max()
+        .unwrap_or_default();
+
+    let atlas_width = max_width.max((total_area as f64).sqrt().ceil() as u32);
+
+    let mut positions = Vec::with_capacity(sprites.len());
+    let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+
+    for sprite in &sprites {
+        let (width, height) = sprite.image.dimensions();
+
+        if x > 0 && x + width > atlas_width {
+            x = 0;
+            y += shelf_height + PADDING;
+            shelf_height = 0;
+        }
+
+        positions.push((x, y));
+
+        x += width + PADDING;
+        shelf_height = shelf_height.max(height);
+    }
+
+    let atlas_height = y + shelf_height;
+
+    let mut atlas = RgbaImage::new(atlas_width.max(1), atlas_height.max(1));
+    let mut frames = BTreeMap::new();
+
+    for (sprite, (x, y)) in sprites.into_iter().zip(positions) {
+        replace(&mut atlas, &sprite.image, x as i64, y as i64);
+
+        frames.insert(
+            sprite.name,
+            AtlasFrame {
+                x,
+                y,
+                width: sprite.image.width(),
+                height: sprite.image.height(),
+                offset_x: sprite.offset_x,
+                offset_y: sprite.offset_y,
+                source_width: sprite.source_width,
+                source_height: sprite.source_height,
+            },
+        );
+    }
+
+    let index = AtlasIndex {
+        image: image_name,
+        width: atlas.width(),
+        height: atlas.height(),
+        frames,
+    };
+
+    (atlas, index)
+}
+
+pub fn pack_atlas(model_path: &Path) -> Result<Vec<PathBuf>, String> {
+    let resources_path = model_path.join("resources");
+
+    let mut index_paths = vec![];
+
+    for group_name in KEY_GROUPS {
+        let sprites = load_sprites(&resources_path.join(group_name));
+
+        if sprites.is_empty() {
+            continue;
+        }
+
+        let image_name = format!("{}.atlas.png", group_name);
+        let index_path = resources_path.join(format!("{}.atlas.json", group_name));
+
+        let (atlas, index) = pack_sprites(sprites, image_name.clone());
+
+        atlas
+            .save(resources_path.join(image_name))
+            .map_err(|err| err.to_string())?;
+
+        let content = serde_json::to_string(&index).map_err(|err| err.to_string())?;
+
+        write(&index_path, content).map_err(|err| err.to_string())?;
+
+        index_paths.push(index_path);
+    }
+
+    Ok(index_paths)
+}
+
+#[command]
+pub async fn pack_key_atlas(path: PathBuf) -> Result<Vec<PathBuf>, String> {
+    spawn_blocking(move || pack_atlas(&path))
+        .await
+        .map_err(|err| err.to_string())?
+}
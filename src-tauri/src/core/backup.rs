@@ -0,0 +1,400 @@
+use super::{model::custom_models_dir, settings::SETTINGS_VERSION};
+use fs_extra::dir::{DirOptions, get_dir_content2};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{self, Read, Seek, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tauri::{AppHandle, Manager, Runtime, async_runtime::spawn_blocking, command};
+use tauri_plugin_pinia::ManagerExt;
+use zip::{ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+const BACKUP_FORMAT: u64 = 1;
+const MANIFEST_NAME: &str = "manifest.json";
+const STORES_PREFIX: &str = "stores";
+const CONFIG_PREFIX: &str = "config";
+const MODELS_PREFIX: &str = "custom-models";
+const PENDING_RESTORE_DIR: &str = "pending-restore";
+const MODEL_STORE_FILE: &str = "model.json";
+const CONFIG_FILES: [&str; 2] = ["settings.json", "window-layouts.json"];
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupManifest {
+    format: u64,
+    app_version: String,
+    settings_version: u64,
+    created_at: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    app_version: String,
+    created_at: u64,
+    stores: Vec<String>,
+    config_files: Vec<String>,
+    models: Vec<String>,
+    file_count: usize,
+    total_bytes: u64,
+    compatible: bool,
+    reason: Option<String>,
+}
+
+struct BackupPaths {
+    stores: PathBuf,
+    config: PathBuf,
+    models: PathBuf,
+    pending: PathBuf,
+}
+
+fn backup_paths<R: Runtime>(app_handle: &AppHandle<R>) -> Result<BackupPaths, String> {
+    let path = app_handle.path();
+
+    let config = path.app_config_dir().map_err(|err| err.to_string())?;
+    let app_data_dir = path.app_data_dir().map_err(|err| err.to_string())?;
+
+    Ok(BackupPaths {
+        stores: app_handle.pinia().path(),
+        config,
+        models: custom_models_dir(app_handle)?,
+        pending: app_data_dir.join(PENDING_RESTORE_DIR),
+    })
+}
+
+fn add_file<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    path: &Path,
+    name: &str,
+) -> Result<(), String> {
+    let mut file = File::open(path).map_err(|err| err.to_string())?;
+
+    zip.start_file(name, SimpleFileOptions::default())
+        .map_err(|err| err.to_string())?;
+
+    io::copy(&mut file, zip).map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+fn add_dir<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    dir: &Path,
+    prefix: &str,
+) -> Result<(), String> {
+    if !dir.exists() {
+        return Ok(());
+    }
+
+    let content = get_dir_content2(dir, &DirOptions::new()).map_err(|err| err.to_string())?;
+
+    for file in content.files {
+        let path = PathBuf::from(file);
+
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+
+        // Archive entries always use forward slashes
+        let relative = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        add_file(zip, &path, &format!("{}/{}", prefix, relative))?;
+    }
+
+    Ok(())
+}
+
+fn write_backup(
+    paths: &BackupPaths,
+    manifest: &BackupManifest,
+    target: &Path,
+) -> Result<(), String> {
+    let file = File::create(target).map_err(|err| err.to_string())?;
+
+    let mut zip = ZipWriter::new(file);
+
+    zip.start_file(MANIFEST_NAME, SimpleFileOptions::default())
+        .map_err(|err| err.to_string())?;
+
+    serde_json::to_writer_pretty(&mut zip, manifest).map_err(|err| err.to_string())?;
+
+    add_dir(&mut zip, &paths.stores, STORES_PREFIX)?;
+
+    for name in CONFIG_FILES {
+        let path = paths.config.join(name);
+
+        if path.exists() {
+            add_file(&mut zip, &path, &format!("{}/{}", CONFIG_PREFIX, name))?;
+        }
+    }
+
+    add_dir(&mut zip, &paths.models, MODELS_PREFIX)?;
+
+    zip.finish().map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+fn read_manifest<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<BackupManifest, String> {
+    let file = archive
+        .by_name(MANIFEST_NAME)
+        .map_err(|_| String::from("Not a BongoCat backup"))?;
+
+    serde_json::from_reader(file).map_err(|err| err.to_string())
+}
+
+fn check_compatibility(manifest: &BackupManifest) -> Option<String> {
+    if manifest.format > BACKUP_FORMAT {
+        return Some(format!(
+            "Backup format {} is newer than the supported format {}",
+            manifest.format, BACKUP_FORMAT
+        ));
+    }
+
+    if manifest.settings_version > SETTINGS_VERSION {
+        return Some(format!(
+            "Backup was created by a newer version ({})",
+            manifest.app_version
+        ));
+    }
+
+    None
+}
+
+fn summarize(path: &Path) -> Result<BackupSummary, String> {
+    let file = File::open(path).map_err(|err| err.to_string())?;
+
+    let mut archive = ZipArchive::new(file).map_err(|err| err.to_string())?;
+
+    let manifest = read_manifest(&mut archive)?;
+
+    let mut stores = vec![];
+    let mut config_files = vec![];
+    let mut models = BTreeSet::new();
+    let mut file_count = 0;
+    let mut total_bytes = 0;
+
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).map_err(|err| err.to_string())?;
+
+        if entry.is_dir() {
+            continue;
+        }
+
+        let Some(name) = entry.enclosed_name() else {
+            return Err(format!("Unsafe path in backup: {}", entry.name()));
+        };
+
+        let mut components = name.iter().map(|component| component.to_string_lossy());
+
+        match (components.next().as_deref(), components.next()) {
+            (Some(STORES_PREFIX), Some(store)) => stores.push(store.to_string()),
+            (Some(CONFIG_PREFIX), Some(config)) => config_files.push(config.to_string()),
+            (Some(MODELS_PREFIX), Some(model)) => {
+                models.insert(model.to_string());
+            }
+            _ => continue,
+        }
+
+        file_count += 1;
+        total_bytes += entry.size();
+    }
+
+    let reason = check_compatibility(&manifest);
+
+    Ok(BackupSummary {
+        app_version: manifest.app_version,
+        created_at: manifest.created_at,
+        stores,
+        config_files,
+        models: models.into_iter().collect(),
+        file_count,
+        total_bytes,
+        compatible: reason.is_none(),
+        reason,
+    })
+}
+
+fn extract(path: &Path, target: &Path) -> Result<(), String> {
+    let file = File::open(path).map_err(|err| err.to_string())?;
+
+    let mut archive = ZipArchive::new(file).map_err(|err| err.to_string())?;
+
+    if let Some(reason) = check_compatibility(&read_manifest(&mut archive)?) {
+        return Err(reason);
+    }
+
+    // Extract next to the target first so an interrupted restore is never picked up
+    let staging = target.with_extension("tmp");
+
+    for dir in [&staging, target] {
+        if dir.exists() {
+            fs::remove_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+    }
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|err| err.to_string())?;
+
+        let Some(name) = entry.enclosed_name() else {
+            return Err(format!("Unsafe path in backup: {}", entry.name()));
+        };
+
+        let output = staging.join(name);
+
+        if entry.is_dir() {
+            fs::create_dir_all(&output).map_err(|err| err.to_string())?;
+
+            continue;
+        }
+
+        if let Some(parent) = output.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+
+        let mut file = File::create(&output).map_err(|err| err.to_string())?;
+
+        io::copy(&mut entry, &mut file).map_err(|err| err.to_string())?;
+    }
+
+    fs::rename(&staging, target).map_err(|err| err.to_string())
+}
+
+fn replace_files(from: &Path, to: &Path) -> Result<(), String> {
+    if !from.exists() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(to).map_err(|err| err.to_string())?;
+
+    for entry in fs::read_dir(from).map_err(|err| err.to_string())? {
+        let entry = entry.map_err(|err| err.to_string())?;
+
+        fs::copy(entry.path(), to.join(entry.file_name())).map_err(|err| err.to_string())?;
+    }
+
+    Ok(())
+}
+
+fn relocate_model(model: &mut Value, models_dir: &Path) {
+    if model.get("isPreset").and_then(Value::as_bool) != Some(false) {
+        return;
+    }
+
+    let Some(id) = model.get("id").and_then(Value::as_str) else {
+        return;
+    };
+
+    let path = models_dir.join(id).to_string_lossy().into_owned();
+
+    model["path"] = Value::from(path);
+}
+
+// Custom model paths are absolute, so they are pointed at this machine's models directory
+fn relocate_models(store_path: &Path, models_dir: &Path) -> Result<(), String> {
+    if !store_path.exists() {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(store_path).map_err(|err| err.to_string())?;
+
+    let mut store: Value = serde_json::from_str(&content).map_err(|err| err.to_string())?;
+
+    if let Some(models) = store.get_mut("models").and_then(Value::as_array_mut) {
+        for model in models {
+            relocate_model(model, models_dir);
+        }
+    }
+
+    if let Some(model) = store.get_mut("currentModel") {
+        relocate_model(model, models_dir);
+    }
+
+    let content = serde_json::to_vec(&store).map_err(|err| err.to_string())?;
+
+    fs::write(store_path, content).map_err(|err| err.to_string())
+}
+
+/// Applies a restore staged by `restore_backup`, before any store is loaded.
+pub fn apply_pending_restore<R: Runtime>(app_handle: &AppHandle<R>) -> Result<(), String> {
+    let paths = backup_paths(app_handle)?;
+
+    if !paths.pending.exists() {
+        return Ok(());
+    }
+
+    replace_files(&paths.pending.join(STORES_PREFIX), &paths.stores)?;
+    replace_files(&paths.pending.join(CONFIG_PREFIX), &paths.config)?;
+
+    relocate_models(&paths.stores.join(MODEL_STORE_FILE), &paths.models)?;
+
+    let models = paths.pending.join(MODELS_PREFIX);
+
+    if models.exists() {
+        if paths.models.exists() {
+            fs::remove_dir_all(&paths.models).map_err(|err| err.to_string())?;
+        }
+
+        if let Some(parent) = paths.models.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+
+        fs::rename(&models, &paths.models).map_err(|err| err.to_string())?;
+    }
+
+    fs::remove_dir_all(&paths.pending).map_err(|err| err.to_string())
+}
+
+#[command]
+pub async fn export_backup<R: Runtime>(
+    app_handle: AppHandle<R>,
+    path: PathBuf,
+) -> Result<(), String> {
+    let paths = backup_paths(&app_handle)?;
+
+    let created_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| err.to_string())?
+        .as_secs();
+
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT,
+        app_version: app_handle.package_info().version.to_string(),
+        settings_version: SETTINGS_VERSION,
+        created_at,
+    };
+
+    spawn_blocking(move || write_backup(&paths, &manifest, &path))
+        .await
+        .map_err(|err| err.to_string())?
+}
+
+#[command]
+pub async fn inspect_backup(path: PathBuf) -> Result<BackupSummary, String> {
+    spawn_blocking(move || summarize(&path))
+        .await
+        .map_err(|err| err.to_string())?
+}
+
+#[command]
+pub async fn restore_backup<R: Runtime>(
+    app_handle: AppHandle<R>,
+    path: PathBuf,
+) -> Result<(), String> {
+    let paths = backup_paths(&app_handle)?;
+
+    spawn_blocking(move || extract(&path, &paths.pending))
+        .await
+        .map_err(|err| err.to_string())??;
+
+    // The stores are kept in memory while running, so the files are swapped in on the next launch
+    app_handle.restart();
+}
@@ -0,0 +1,120 @@
+use super::{idle, rules, script, server::publish, settings, setup};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+use tauri::{
+    AppHandle, Emitter, EventTarget, Manager, PhysicalPosition, Runtime, WebviewUrl,
+    WebviewWindowBuilder, command,
+};
+use tauri_plugin_custom_window::{CAT_WINDOW_PREFIX, MAIN_WINDOW_LABEL, is_cat_window};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InputSource {
+    All,
+    Keyboard,
+    Mouse,
+    Gamepad,
+}
+
+static INPUT_SOURCES: LazyLock<Mutex<HashMap<String, InputSource>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn accepts_input(label: &str, source: InputSource) -> bool {
+    if !is_cat_window(label) {
+        return true;
+    }
+
+    let input_sources = INPUT_SOURCES.lock().unwrap();
+
+    match input_sources.get(label) {
+        Some(InputSource::All) | None => true,
+        Some(bound) => *bound == source,
+    }
+}
+
+pub fn emit_input<R: Runtime, S: Serialize + Clone>(
+    app_handle: &AppHandle<R>,
+    event: &str,
+    source: InputSource,
+    payload: S,
+) {
+    idle::record_activity(app_handle);
+
+    // Privacy mode keeps keystrokes away from server clients, the cat windows still get them
+    if source != InputSource::Keyboard || !settings::privacy_enabled() {
+        publish(event, &payload);
+    }
+
+    script::handle_input(event, &payload);
+
+    rules::handle_input(app_handle, &payload);
+
+    for label in app_handle.webview_windows().into_keys() {
+        if !accepts_input(&label, source) {
+            continue;
+        }
+
+        let _ = app_handle.emit_to(EventTarget::labeled(label), event, payload.clone());
+    }
+}
+
+#[command]
+pub async fn create_cat_window(
+    app_handle: AppHandle,
+    label: String,
+    position: Option<PhysicalPosition<i32>>,
+) -> Result<(), String> {
+    if !label.starts_with(CAT_WINDOW_PREFIX) {
+        return Err(format!("Invalid cat window label: {}", label));
+    }
+
+    if app_handle.get_webview_window(&label).is_some() {
+        return Ok(());
+    }
+
+    let window =
+        WebviewWindowBuilder::new(&app_handle, &label, WebviewUrl::App("index.html/#/".into()))
+            .title("BongoCat")
+            .shadow(false)
+            .always_on_top(true)
+            .transparent(true)
+            .decorations(false)
+            .accept_first_mouse(true)
+            .skip_taskbar(true)
+            .maximizable(false)
+            .build()
+            .map_err(|err| err.to_string())?;
+
+    if let Some(position) = position {
+        window
+            .set_position(position)
+            .map_err(|err| err.to_string())?;
+    }
+
+    app_handle
+        .run_on_main_thread(move || setup::cat_window(&window))
+        .map_err(|err| err.to_string())
+}
+
+#[command]
+pub async fn close_cat_window(app_handle: AppHandle, label: String) -> Result<(), String> {
+    if label == MAIN_WINDOW_LABEL || !is_cat_window(&label) {
+        return Err(format!("Invalid cat window label: {}", label));
+    }
+
+    INPUT_SOURCES.lock().unwrap().remove(&label);
+
+    let Some(window) = app_handle.get_webview_window(&label) else {
+        return Ok(());
+    };
+
+    window.destroy().map_err(|err| err.to_string())
+}
+
+#[command]
+pub async fn set_input_source(label: String, source: InputSource) {
+    INPUT_SOURCES.lock().unwrap().insert(label, source);
+}
@@ -0,0 +1,101 @@
+use super::control::{self, ControlCommand};
+use std::{process, sync::Mutex};
+use tauri::{AppHandle, Runtime, command};
+
+const USAGE: &str = "Usage: bongo-cat [COMMAND]
+
+Commands:
+  show                     Show the cat
+  hide                     Hide the cat
+  toggle                   Toggle the cat's visibility
+  model <id>               Switch to the model with the given id
+  motion <group> <index>   Play a motion, counting from 0 within its group
+  expression <index>       Apply an expression, counting from 0
+  mirror on|off            Turn mirror mode on or off
+
+Indexes count from 0, as in the HTTP API, behavior rules and scripts.
+Commands are forwarded to the running instance. Without a command, it opens its preferences.";
+
+const SUBCOMMANDS: [&str; 7] = [
+    "show",
+    "hide",
+    "toggle",
+    "model",
+    "motion",
+    "expression",
+    "mirror",
+];
+
+// A command given to the first instance waits until the main window can handle it
+static PENDING_COMMAND: Mutex<Option<ControlCommand>> = Mutex::new(None);
+
+fn parse_index(value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("Invalid index: {}", value))
+}
+
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Option<ControlCommand>, String> {
+    // macOS passes a process serial number to apps launched from Finder
+    let args: Vec<String> = args
+        .into_iter()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("-psn_"))
+        .collect();
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    // Launchers pass their own arguments, like a desktop file's %U or autostart and updater flags
+    if !args.first().is_some_and(|arg| SUBCOMMANDS.contains(arg)) {
+        return Ok(None);
+    }
+
+    let command = match args.as_slice() {
+        [] => return Ok(None),
+        ["show"] => ControlCommand::Show,
+        ["hide"] => ControlCommand::Hide,
+        ["toggle"] => ControlCommand::Toggle,
+        ["model", id] => ControlCommand::Model(id.to_string()),
+        ["motion", group, index] => ControlCommand::Motion {
+            group: group.to_string(),
+            index: parse_index(index)?,
+        },
+        ["expression", index] => ControlCommand::Expression(parse_index(index)?),
+        ["mirror", "on"] => ControlCommand::Mirror(true),
+        ["mirror", "off"] => ControlCommand::Mirror(false),
+        _ => return Err(format!("Unknown command: {}", args.join(" "))),
+    };
+
+    Ok(Some(command))
+}
+
+pub fn check_env() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if matches!(
+        args.get(1).map(String::as_str),
+        Some("-h" | "--help" | "help")
+    ) {
+        println!("{}", USAGE);
+
+        process::exit(0);
+    }
+
+    match parse(args) {
+        Ok(command) => *PENDING_COMMAND.lock().unwrap() = command,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+
+            process::exit(2);
+        }
+    }
+}
+
+#[command]
+pub async fn run_pending_command<R: Runtime>(app_handle: AppHandle<R>) {
+    let Some(command) = PENDING_COMMAND.lock().unwrap().take() else {
+        return;
+    };
+
+    control::dispatch(&app_handle, command);
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{AppHandle, Emitter, EventTarget, Runtime};
+use tauri_plugin_custom_window::MAIN_WINDOW_LABEL;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "camelCase")]
+pub enum ControlCommand {
+    Show,
+    Hide,
+    Toggle,
+    Model(String),
+    Motion { group: String, index: usize },
+    Expression(usize),
+    Mirror(bool),
+    PassThrough(bool),
+    AlwaysOnTop(bool),
+    Scale(u32),
+    Opacity(u32),
+}
+
+pub fn dispatch<R: Runtime>(app_handle: &AppHandle<R>, command: ControlCommand) {
+    let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+
+    // Motions and expressions go through the same events the preference window uses
+    let _ = match command {
+        ControlCommand::Motion { group, index } => app_handle.emit_to(
+            target,
+            "start-motion",
+            json!({ "group": group, "no": index }),
+        ),
+        ControlCommand::Expression(index) => app_handle.emit_to(target, "set-expression", index),
+        command => app_handle.emit_to(target, "control-command", command),
+    };
+}
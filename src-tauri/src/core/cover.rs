@@ -0,0 +1,104 @@
+use image::{
+    Rgba, RgbaImage,
+    imageops::{FilterType, overlay, resize},
+};
+use std::{
+    fs::{create_dir_all, read_dir},
+    path::{Path, PathBuf},
+};
+use tauri::{async_runtime::spawn_blocking, command};
+
+const COVER_WIDTH: u32 = 612;
+const COVER_HEIGHT: u32 = 354;
+pub const COVER_FILE_NAME: &str = "cover.png";
+const PLACEHOLDER_TOP: [u8; 3] = [255, 236, 210];
+const PLACEHOLDER_BOTTOM: [u8; 3] = [252, 182, 159];
+
+fn open_image(path: &Path) -> Option<RgbaImage> {
+    image::open(path).ok().map(|image| image.to_rgba8())
+}
+
+fn find_key_image(resources_path: &Path) -> Option<PathBuf> {
+    for group_name in ["left-keys", "right-keys"] {
+        let Ok(entries) = read_dir(resources_path.join(group_name)) else {
+            continue;
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+            .collect();
+
+        paths.sort();
+
+        if let Some(path) = paths.into_iter().next() {
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+fn create_placeholder(width: u32, height: u32) -> RgbaImage {
+    RgbaImage::from_fn(width, height, |_, y| {
+        let ratio = y as f32 / height.max(1) as f32;
+
+        let mix = |index: usize| {
+            let top = PLACEHOLDER_TOP[index] as f32;
+            let bottom = PLACEHOLDER_BOTTOM[index] as f32;
+
+            (top + (bottom - top) * ratio).round() as u8
+        };
+
+        Rgba([mix(0), mix(1), mix(2), 255])
+    })
+}
+
+fn compose_cover(model_path: &Path) -> RgbaImage {
+    let resources_path = model_path.join("resources");
+
+    let mut cover = open_image(&resources_path.join("background.png"))
+        .unwrap_or_else(|| create_placeholder(COVER_WIDTH, COVER_HEIGHT));
+
+    let key_image = find_key_image(&resources_path).and_then(|path| open_image(&path));
+
+    if let Some(key_image) = key_image {
+        let (width, height) = cover.dimensions();
+
+        let key_image = if key_image.dimensions() == (width, height) {
+            key_image
+        } else {
+            resize(&key_image, width, height, FilterType::Triangle)
+        };
+
+        overlay(&mut cover, &key_image, 0, 0);
+    }
+
+    cover
+}
+
+pub fn generate_cover(model_path: &Path, force: bool) -> Result<PathBuf, String> {
+    let cover_path = model_path.join("resources").join(COVER_FILE_NAME);
+
+    if cover_path.exists() && !force {
+        return Ok(cover_path);
+    }
+
+    if let Some(parent) = cover_path.parent() {
+        create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+
+    compose_cover(model_path)
+        .save(&cover_path)
+        .map_err(|err| err.to_string())?;
+
+    Ok(cover_path)
+}
+
+#[command]
+pub async fn generate_model_cover(path: PathBuf, force: bool) -> Result<PathBuf, String> {
+    spawn_blocking(move || generate_cover(&path, force))
+        .await
+        .map_err(|err| err.to_string())?
+}
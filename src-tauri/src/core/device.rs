@@ -1,8 +1,9 @@
+use super::cat_window::{InputSource, emit_input};
 use rdev::{Event, EventType, listen};
 use serde::Serialize;
 use serde_json::{Value, json};
 use std::sync::atomic::{AtomicBool, Ordering};
-use tauri::{AppHandle, Emitter, Runtime, command};
+use tauri::{AppHandle, Runtime, command};
 
 #[derive(Debug, Clone, Serialize)]
 pub enum DeviceEventKind {
@@ -54,7 +55,14 @@ pub async fn start_device_listening<R: Runtime>(app_handle: AppHandle<R>) -> Res
             _ => return,
         };
 
-        let _ = app_handle.emit("device-changed", device_event);
+        let source = match device_event.kind {
+            DeviceEventKind::KeyboardPress | DeviceEventKind::KeyboardRelease => {
+                InputSource::Keyboard
+            }
+            _ => InputSource::Mouse,
+        };
+
+        emit_input(&app_handle, "device-changed", source, device_event);
     };
 
     listen(callback).map_err(|err| format!("Failed to listen device: {:?}", err))?;
@@ -1,7 +1,8 @@
+use super::cat_window::{InputSource, emit_input};
 use gilrs::{EventType, Gilrs};
 use serde::Serialize;
 use std::sync::atomic::{AtomicBool, Ordering};
-use tauri::{AppHandle, Emitter, Runtime, command};
+use tauri::{AppHandle, Runtime, command};
 
 static IS_LISTENING: AtomicBool = AtomicBool::new(false);
 
@@ -44,7 +45,12 @@ pub async fn start_gamepad_listing<R: Runtime>(app_handle: AppHandle<R>) -> Resu
                 _ => continue,
             };
 
-            let _ = app_handle.emit("gamepad-changed", gamepad_event);
+            emit_input(
+                &app_handle,
+                "gamepad-changed",
+                InputSource::Gamepad,
+                gamepad_event,
+            );
         }
     }
 
@@ -0,0 +1,99 @@
+use super::cover::COVER_FILE_NAME;
+use notify_debouncer_mini::{
+    DebounceEventResult, Debouncer, new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+};
+use serde::Serialize;
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+use tauri::{AppHandle, Emitter, Runtime, command};
+
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(300);
+const GENERATED_INFIX: &str = ".atlas.";
+
+const WATCHED_SUFFIXES: &[&str] = &[
+    ".model3.json",
+    ".moc3",
+    ".physics3.json",
+    ".motion3.json",
+    ".exp3.json",
+    ".png",
+    ".jpg",
+    ".jpeg",
+    ".webp",
+];
+
+static WATCHER: Mutex<Option<Debouncer<RecommendedWatcher>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelReloadEvent {
+    path: PathBuf,
+    files: Vec<PathBuf>,
+}
+
+fn is_watched_file(path: &Path) -> bool {
+    let Some(file_name) = path.file_name() else {
+        return false;
+    };
+
+    let file_name = file_name.to_string_lossy().to_lowercase();
+
+    // Atlases and covers are written by the app itself and must not trigger another reload
+    if file_name.contains(GENERATED_INFIX) || file_name == COVER_FILE_NAME {
+        return false;
+    }
+
+    WATCHED_SUFFIXES
+        .iter()
+        .any(|suffix| file_name.ends_with(suffix))
+}
+
+#[command]
+pub async fn start_model_watching<R: Runtime>(
+    app_handle: AppHandle<R>,
+    path: PathBuf,
+) -> Result<(), String> {
+    let model_path = path.clone();
+
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |result: DebounceEventResult| {
+        let Ok(events) = result else {
+            return;
+        };
+
+        let files: Vec<PathBuf> = events
+            .into_iter()
+            .map(|event| event.path)
+            .filter(|path| is_watched_file(path))
+            .collect();
+
+        if files.is_empty() {
+            return;
+        }
+
+        let _ = app_handle.emit(
+            "model-reload",
+            ModelReloadEvent {
+                path: model_path.clone(),
+                files,
+            },
+        );
+    })
+    .map_err(|err| err.to_string())?;
+
+    debouncer
+        .watcher()
+        .watch(&path, RecursiveMode::Recursive)
+        .map_err(|err| err.to_string())?;
+
+    *WATCHER.lock().unwrap() = Some(debouncer);
+
+    Ok(())
+}
+
+#[command]
+pub async fn stop_model_watching() {
+    WATCHER.lock().unwrap().take();
+}
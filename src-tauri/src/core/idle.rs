@@ -0,0 +1,93 @@
+use super::server::publish;
+use serde::Serialize;
+use std::{
+    sync::{
+        LazyLock, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Emitter, Runtime, command};
+
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_THRESHOLD: u64 = 300;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IdleEvent {
+    idle_duration: u64,
+}
+
+static LAST_ACTIVITY: LazyLock<Mutex<Instant>> = LazyLock::new(|| Mutex::new(Instant::now()));
+
+static THRESHOLD: AtomicU64 = AtomicU64::new(DEFAULT_THRESHOLD);
+
+static IS_IDLE: AtomicBool = AtomicBool::new(false);
+
+pub fn idle_duration() -> Duration {
+    LAST_ACTIVITY.lock().unwrap().elapsed()
+}
+
+fn emit<R: Runtime>(app_handle: &AppHandle<R>, event: &str, idle_duration: Duration) {
+    let payload = IdleEvent {
+        idle_duration: idle_duration.as_millis() as u64,
+    };
+
+    publish(event, &payload);
+
+    let _ = app_handle.emit(event, payload);
+}
+
+pub fn record_activity<R: Runtime>(app_handle: &AppHandle<R>) {
+    let idle_duration = {
+        let mut last_activity = LAST_ACTIVITY.lock().unwrap();
+
+        let idle_duration = last_activity.elapsed();
+
+        *last_activity = Instant::now();
+
+        idle_duration
+    };
+
+    if IS_IDLE.swap(false, Ordering::SeqCst) {
+        emit(app_handle, "active", idle_duration);
+    }
+}
+
+pub fn init<R: Runtime>(app_handle: &AppHandle<R>) {
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || {
+        loop {
+            thread::sleep(TICK_INTERVAL);
+
+            let threshold = THRESHOLD.load(Ordering::SeqCst);
+
+            // A threshold of zero turns idle detection off
+            if threshold == 0 {
+                continue;
+            }
+
+            let idle_duration = idle_duration();
+
+            if idle_duration < Duration::from_secs(threshold) {
+                continue;
+            }
+
+            if !IS_IDLE.swap(true, Ordering::SeqCst) {
+                emit(&app_handle, "idle", idle_duration);
+            }
+        }
+    });
+}
+
+#[command]
+pub async fn set_idle_threshold(seconds: u64) {
+    THRESHOLD.store(seconds, Ordering::SeqCst);
+}
+
+#[command]
+pub async fn get_idle_duration() -> u64 {
+    idle_duration().as_millis() as u64
+}
@@ -1,4 +1,19 @@
+pub mod atlas;
+pub mod backup;
+pub mod cat_window;
+pub mod cli;
+pub mod control;
+pub mod cover;
 pub mod device;
 pub mod gamepad;
+pub mod hot_reload;
+pub mod idle;
+pub mod model;
+pub mod overlay;
 pub mod prevent_default;
+pub mod rules;
+pub mod script;
+pub mod server;
+pub mod settings;
 pub mod setup;
+pub mod tray;
@@ -0,0 +1,220 @@
+use fs_extra::dir::{CopyOptions, copy, get_size};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::{Path, PathBuf},
+};
+use tauri::{AppHandle, Manager, Runtime, command};
+
+const CUSTOM_MODELS_DIR: &str = "custom-models";
+const MANIFEST_FILE: &str = "bongo-cat.json";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelMode {
+    Standard,
+    Keyboard,
+    Gamepad,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomModel {
+    id: String,
+    name: String,
+    path: PathBuf,
+    mode: ModelMode,
+    size: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ModelManifest {
+    name: Option<String>,
+}
+
+pub fn custom_models_dir<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|err| err.to_string())?;
+
+    Ok(app_data_dir.join(CUSTOM_MODELS_DIR))
+}
+
+fn model_dir<R: Runtime>(app_handle: &AppHandle<R>, id: &str) -> Result<PathBuf, String> {
+    let is_valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-');
+
+    if !is_valid {
+        return Err(format!("Invalid model id: {}", id));
+    }
+
+    Ok(custom_models_dir(app_handle)?.join(id))
+}
+
+fn read_manifest(path: &Path) -> ModelManifest {
+    read_to_string(path.join(MANIFEST_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn detect_mode(path: &Path) -> ModelMode {
+    let Ok(entries) = read_dir(path.join("resources").join("right-keys")) else {
+        return ModelMode::Standard;
+    };
+
+    let mut mode = ModelMode::Standard;
+
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+
+        let Some(file_stem) = entry_path.file_stem() else {
+            continue;
+        };
+
+        if file_stem == "East" {
+            return ModelMode::Gamepad;
+        }
+
+        mode = ModelMode::Keyboard;
+    }
+
+    mode
+}
+
+fn read_model(id: &str, path: PathBuf) -> CustomModel {
+    let manifest = read_manifest(&path);
+
+    CustomModel {
+        id: id.to_string(),
+        name: manifest.name.unwrap_or_else(|| id.to_string()),
+        mode: detect_mode(&path),
+        size: get_size(&path).unwrap_or_default(),
+        path,
+    }
+}
+
+fn read_models<R: Runtime>(app_handle: &AppHandle<R>) -> Result<Vec<CustomModel>, String> {
+    let models_dir = custom_models_dir(app_handle)?;
+
+    if !models_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let entries = read_dir(models_dir).map_err(|err| err.to_string())?;
+
+    let mut models = vec![];
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if !path.is_dir() {
+            continue;
+        }
+
+        let id = entry.file_name().to_string_lossy().to_string();
+
+        models.push(read_model(&id, path));
+    }
+
+    Ok(models)
+}
+
+#[command]
+pub async fn list_custom_models<R: Runtime>(
+    app_handle: AppHandle<R>,
+) -> Result<Vec<CustomModel>, String> {
+    read_models(&app_handle)
+}
+
+#[command]
+pub async fn delete_custom_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    let path = model_dir(&app_handle, &id)?;
+
+    if !path.exists() {
+        return Ok(());
+    }
+
+    remove_dir_all(path).map_err(|err| err.to_string())
+}
+
+#[command]
+pub async fn duplicate_custom_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    id: String,
+    new_id: String,
+) -> Result<CustomModel, String> {
+    let from_path = model_dir(&app_handle, &id)?;
+    let to_path = model_dir(&app_handle, &new_id)?;
+
+    if to_path.exists() {
+        return Err(format!("Model already exists: {}", new_id));
+    }
+
+    let mut options = CopyOptions::new();
+    options.content_only = true;
+
+    create_dir_all(&to_path).map_err(|err| err.to_string())?;
+
+    if let Err(err) = copy(&from_path, &to_path, &options) {
+        let _ = remove_dir_all(&to_path);
+
+        return Err(err.to_string());
+    }
+
+    Ok(read_model(&new_id, to_path))
+}
+
+#[command]
+pub async fn rename_custom_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    id: String,
+    name: String,
+) -> Result<CustomModel, String> {
+    let path = model_dir(&app_handle, &id)?;
+
+    if !path.exists() {
+        return Err(format!("Model not found: {}", id));
+    }
+
+    let mut manifest = read_manifest(&path);
+
+    let name = name.trim();
+    manifest.name = (!name.is_empty()).then(|| name.to_string());
+
+    let content = serde_json::to_string_pretty(&manifest).map_err(|err| err.to_string())?;
+
+    write(path.join(MANIFEST_FILE), content).map_err(|err| err.to_string())?;
+
+    Ok(read_model(&id, path))
+}
+
+#[command]
+pub async fn collect_custom_models<R: Runtime>(
+    app_handle: AppHandle<R>,
+    referenced_ids: Vec<String>,
+    dry_run: bool,
+) -> Result<Vec<CustomModel>, String> {
+    let referenced_ids: HashSet<String> = referenced_ids.into_iter().collect();
+
+    let orphaned_models: Vec<CustomModel> = read_models(&app_handle)?
+        .into_iter()
+        .filter(|model| !referenced_ids.contains(&model.id))
+        .collect();
+
+    if dry_run {
+        return Ok(orphaned_models);
+    }
+
+    for model in &orphaned_models {
+        remove_dir_all(&model.path).map_err(|err| err.to_string())?;
+    }
+
+    Ok(orphaned_models)
+}
@@ -0,0 +1,200 @@
+use super::server::{ServerState, publish, require_token};
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{StatusCode, Uri, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path as FsPath, PathBuf},
+    sync::Mutex,
+};
+use tauri::command;
+
+const OVERLAY_PAGE: &str = "overlay.html";
+const KEY_GROUPS: [&str; 2] = ["left-keys", "right-keys"];
+const IMAGE_EXTENSIONS: [&str; 6] = ["png", "jpg", "jpeg", "webp", "gif", "avif"];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlayScene {
+    model_path: PathBuf,
+    mirror: bool,
+    mouse_mirror: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MonitorArea {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SceneResponse {
+    model_file: String,
+    background: Option<String>,
+    keys: HashMap<String, String>,
+    mirror: bool,
+    mouse_mirror: bool,
+    monitors: Vec<MonitorArea>,
+}
+
+static SCENE: Mutex<Option<OverlayScene>> = Mutex::new(None);
+
+fn file_names(path: &FsPath) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(path) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect()
+}
+
+fn is_image(name: &str) -> bool {
+    let extension = name.rsplit('.').next().unwrap_or_default();
+
+    IMAGE_EXTENSIONS
+        .iter()
+        .any(|image| image.eq_ignore_ascii_case(extension))
+}
+
+fn mime_type(path: &FsPath) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "json" => "application/json",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "avif" => "image/avif",
+        "wav" => "audio/wav",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        _ => "application/octet-stream",
+    }
+}
+
+async fn scene(State(state): State<ServerState>) -> Response {
+    let Some(scene) = SCENE.lock().unwrap().clone() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let Some(model_file) = file_names(&scene.model_path)
+        .into_iter()
+        .find(|name| name.ends_with(".model3.json"))
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let resources = scene.model_path.join("resources");
+
+    let background = resources
+        .join("background.png")
+        .exists()
+        .then(|| String::from("resources/background.png"));
+
+    let mut keys = HashMap::new();
+
+    for group in KEY_GROUPS {
+        for name in file_names(&resources.join(group)) {
+            if !is_image(&name) {
+                continue;
+            }
+
+            let key = name.split('.').next().unwrap_or_default().to_string();
+
+            keys.insert(key, format!("resources/{}/{}", group, name));
+        }
+    }
+
+    let monitors = state
+        .app_handle
+        .available_monitors()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|monitor| MonitorArea {
+            x: monitor.position().x,
+            y: monitor.position().y,
+            width: monitor.size().width,
+            height: monitor.size().height,
+        })
+        .collect();
+
+    Json(SceneResponse {
+        model_file,
+        background,
+        keys,
+        mirror: scene.mirror,
+        mouse_mirror: scene.mouse_mirror,
+        monitors,
+    })
+    .into_response()
+}
+
+async fn model_file(Path(path): Path<String>) -> Response {
+    let Some(root) = SCENE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|scene| scene.model_path.clone())
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let (Ok(root), Ok(file)) = (root.canonicalize(), root.join(&path).canonicalize()) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // Only files inside the current model may be served
+    if !file.starts_with(&root) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    match tokio::fs::read(&file).await {
+        Ok(bytes) => ([(CONTENT_TYPE, mime_type(&file))], bytes).into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+// Assets are only embedded in release builds, so under `tauri dev` the overlay page is a 404
+async fn asset(State(state): State<ServerState>, uri: Uri) -> Response {
+    let path = match uri.path().trim_start_matches('/') {
+        "" => OVERLAY_PAGE,
+        path => path,
+    };
+
+    match state.app_handle.asset_resolver().get(path.to_string()) {
+        Some(asset) => ([(CONTENT_TYPE, asset.mime_type)], asset.bytes).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+pub(super) fn router(state: &ServerState) -> Router<ServerState> {
+    // The frontend bundle is public, while the scene and model files require the token
+    let router = Router::new()
+        .route("/api/overlay/scene", get(scene))
+        .route("/api/overlay/files/{*path}", get(model_file));
+
+    require_token(router, state).fallback(get(asset))
+}
+
+#[command]
+pub async fn set_overlay_scene(scene: Option<OverlayScene>) {
+    *SCENE.lock().unwrap() = scene;
+
+    publish("overlay-scene", &());
+}
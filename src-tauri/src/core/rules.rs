@@ -0,0 +1,318 @@
+use super::{
+    control::{self, ControlCommand},
+    idle,
+};
+use notify_debouncer_mini::{
+    DebounceEventResult, Debouncer, new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Manager, Runtime};
+
+const RULE_FILES: [&str; 2] = ["rules.toml", "rules.json"];
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(300);
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+const TYPING_WINDOW: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum Trigger {
+    KeyPress { key: String },
+    MousePress { button: String },
+    TypingSpeed { above: f64 },
+    Idle { seconds: u64 },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum Action {
+    Motion { group: String, index: usize },
+    Expression { index: usize },
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    name: Option<String>,
+    trigger: Trigger,
+    action: Action,
+    #[serde(default = "default_probability")]
+    probability: f64,
+    #[serde(default)]
+    cooldown: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct RuleState {
+    rule: Rule,
+    last_fired: Option<Instant>,
+    // Level triggers such as typing speed and idle fire once, then wait for the condition to reset
+    armed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+enum InputKind {
+    KeyboardPress,
+    MousePress,
+}
+
+static RULES: Mutex<Vec<RuleState>> = Mutex::new(Vec::new());
+
+static KEY_PRESSES: Mutex<VecDeque<Instant>> = Mutex::new(VecDeque::new());
+
+static WATCHER: Mutex<Option<Debouncer<RecommendedWatcher>>> = Mutex::new(None);
+
+fn parse_rules(path: &Path) -> Result<Vec<Rule>, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+    let file: RuleFile = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => toml::from_str(&content).map_err(|err| err.to_string())?,
+        _ => serde_json::from_str(&content).map_err(|err| err.to_string())?,
+    };
+
+    for rule in &file.rules {
+        let name = rule.name.as_deref().unwrap_or("unnamed");
+
+        if !(0.0..=1.0).contains(&rule.probability) {
+            return Err(format!(
+                "Rule {} has an invalid probability: {}",
+                name, rule.probability
+            ));
+        }
+
+        // Rejects negative, infinite, NaN and overflowing values
+        if Duration::try_from_secs_f64(rule.cooldown).is_err() {
+            return Err(format!(
+                "Rule {} has an invalid cooldown: {}",
+                name, rule.cooldown
+            ));
+        }
+    }
+
+    Ok(file.rules)
+}
+
+fn load_rules(dir: &Path) {
+    // The TOML file wins when both exist
+    let Some(path) = RULE_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+    else {
+        RULES.lock().unwrap().clear();
+
+        return;
+    };
+
+    let rules = match parse_rules(&path) {
+        Ok(rules) => rules,
+        Err(err) => {
+            log::error!("Failed to load rules from {}: {}", path.display(), err);
+
+            return;
+        }
+    };
+
+    log::info!("Loaded {} behavior rules", rules.len());
+
+    *RULES.lock().unwrap() = rules
+        .into_iter()
+        .map(|rule| RuleState {
+            rule,
+            last_fired: None,
+            armed: true,
+        })
+        .collect();
+}
+
+fn is_rule_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| RULE_FILES.contains(&name))
+}
+
+// Returns the command to dispatch once the rules lock is released
+fn fire(state: &mut RuleState, now: Instant) -> Option<ControlCommand> {
+    let cooldown = Duration::try_from_secs_f64(state.rule.cooldown).unwrap_or_default();
+
+    if state
+        .last_fired
+        .is_some_and(|last_fired| now.duration_since(last_fired) < cooldown)
+    {
+        return None;
+    }
+
+    if fastrand::f64() >= state.rule.probability {
+        return None;
+    }
+
+    state.last_fired = Some(now);
+
+    let command = match state.rule.action.clone() {
+        Action::Motion { group, index } => ControlCommand::Motion { group, index },
+        Action::Expression { index } => ControlCommand::Expression(index),
+    };
+
+    Some(command)
+}
+
+pub fn handle_input<R: Runtime, S: Serialize>(app_handle: &AppHandle<R>, payload: &S) {
+    let Ok(payload) = serde_json::to_value(payload) else {
+        return;
+    };
+
+    let kind = payload
+        .get("kind")
+        .cloned()
+        .and_then(|kind| serde_json::from_value::<InputKind>(kind).ok());
+
+    let value = payload.get("value").and_then(Value::as_str);
+
+    let now = Instant::now();
+
+    let speed = {
+        let mut key_presses = KEY_PRESSES.lock().unwrap();
+
+        if kind == Some(InputKind::KeyboardPress) {
+            key_presses.push_back(now);
+        }
+
+        while key_presses
+            .front()
+            .is_some_and(|time| now.duration_since(*time) > TYPING_WINDOW)
+        {
+            key_presses.pop_front();
+        }
+
+        key_presses.len() as f64 / TYPING_WINDOW.as_secs_f64()
+    };
+
+    let mut commands = vec![];
+
+    let mut rules = RULES.lock().unwrap();
+
+    for state in rules.iter_mut() {
+        let matched = match &state.rule.trigger {
+            Trigger::KeyPress { key } => {
+                kind == Some(InputKind::KeyboardPress) && value == Some(key.as_str())
+            }
+            Trigger::MousePress { button } => {
+                kind == Some(InputKind::MousePress) && value == Some(button.as_str())
+            }
+            Trigger::TypingSpeed { above } => {
+                if speed <= *above {
+                    state.armed = true;
+
+                    false
+                } else {
+                    std::mem::replace(&mut state.armed, false)
+                }
+            }
+            Trigger::Idle { .. } => {
+                state.armed = true;
+
+                false
+            }
+        };
+
+        if matched {
+            commands.extend(fire(state, now));
+        }
+    }
+
+    drop(rules);
+
+    for command in commands {
+        control::dispatch(app_handle, command);
+    }
+}
+
+fn tick<R: Runtime>(app_handle: &AppHandle<R>) {
+    let now = Instant::now();
+
+    let idle = idle::idle_duration();
+
+    let mut commands = vec![];
+
+    let mut rules = RULES.lock().unwrap();
+
+    for state in rules.iter_mut() {
+        let Trigger::Idle { seconds } = state.rule.trigger else {
+            continue;
+        };
+
+        if state.armed && idle >= Duration::from_secs(seconds) {
+            state.armed = false;
+
+            commands.extend(fire(state, now));
+        }
+    }
+
+    drop(rules);
+
+    for command in commands {
+        control::dispatch(app_handle, command);
+    }
+}
+
+pub fn init<R: Runtime>(app_handle: &AppHandle<R>) -> Result<(), String> {
+    let dir: PathBuf = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|err| err.to_string())?;
+
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+
+    load_rules(&dir);
+
+    let rules_dir = dir.clone();
+
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |result: DebounceEventResult| {
+        let Ok(events) = result else {
+            return;
+        };
+
+        if events.iter().any(|event| is_rule_file(&event.path)) {
+            load_rules(&rules_dir);
+        }
+    })
+    .map_err(|err| err.to_string())?;
+
+    debouncer
+        .watcher()
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|err| err.to_string())?;
+
+    *WATCHER.lock().unwrap() = Some(debouncer);
+
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || {
+        loop {
+            thread::sleep(TICK_INTERVAL);
+
+            tick(&app_handle);
+        }
+    });
+
+    Ok(())
+}
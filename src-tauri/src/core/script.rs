@@ -0,0 +1,313 @@
+use super::control::{self, ControlCommand};
+use notify_debouncer_mini::{
+    DebounceEventResult, Debouncer, new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+};
+use rhai::{
+    AST, CallFnOptions, Dynamic, Engine, Map, Scope, module_resolvers::DummyModuleResolver,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        LazyLock, Mutex,
+        mpsc::{self, SyncSender},
+    },
+    thread,
+    time::Duration,
+};
+use tauri::{AppHandle, Emitter, EventTarget, Manager, Runtime};
+use tauri_plugin_custom_window::MAIN_WINDOW_LABEL;
+
+const SCRIPTS_DIR: &str = "scripts";
+const SCRIPT_EXTENSION: &str = "rhai";
+const HANDLER_NAME: &str = "on_input";
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(300);
+const MAX_OPERATIONS: u64 = 100_000;
+const EVENT_QUEUE_SIZE: usize = 256;
+
+#[derive(Debug, Clone)]
+enum ScriptAction {
+    Motion { group: String, index: usize },
+    Expression(usize),
+    Parameter { id: String, value: f64 },
+    Message(String),
+}
+
+struct Script {
+    name: String,
+    ast: AST,
+}
+
+static ENGINE: LazyLock<Engine> = LazyLock::new(create_engine);
+
+static SCRIPTS: Mutex<Vec<Script>> = Mutex::new(Vec::new());
+
+static ACTIONS: Mutex<Vec<ScriptAction>> = Mutex::new(Vec::new());
+
+static PRESSED_KEYS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+static WATCHER: Mutex<Option<Debouncer<RecommendedWatcher>>> = Mutex::new(None);
+
+static EVENTS: Mutex<Option<SyncSender<(String, Value)>>> = Mutex::new(None);
+
+fn queue(action: ScriptAction) {
+    ACTIONS.lock().unwrap().push(action);
+}
+
+fn create_engine() -> Engine {
+    let mut engine = Engine::new();
+
+    // Scripts can only reach the functions registered below, never the file system
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(32);
+    engine.set_max_string_size(4096);
+    engine.set_max_array_size(1024);
+    engine.set_max_map_size(1024);
+
+    engine.on_print(|text| log::info!("[script] {}", text));
+
+    engine.register_fn("start_motion", |group: &str, index: i64| {
+        if let Ok(index) = usize::try_from(index) {
+            queue(ScriptAction::Motion {
+                group: group.to_string(),
+                index,
+            });
+        }
+    });
+
+    engine.register_fn("set_expression", |index: i64| {
+        if let Ok(index) = usize::try_from(index) {
+            queue(ScriptAction::Expression(index));
+        }
+    });
+
+    engine.register_fn("set_parameter", |id: &str, value: f64| {
+        queue(ScriptAction::Parameter {
+            id: id.to_string(),
+            value,
+        });
+    });
+
+    engine.register_fn("set_parameter", |id: &str, value: i64| {
+        queue(ScriptAction::Parameter {
+            id: id.to_string(),
+            value: value as f64,
+        });
+    });
+
+    engine.register_fn("show_message", |text: &str| {
+        queue(ScriptAction::Message(text.to_string()));
+    });
+
+    engine
+}
+
+fn load_scripts(dir: &Path) {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == SCRIPT_EXTENSION)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    paths.sort();
+
+    let mut scripts = vec![];
+
+    for path in paths {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let ast = match ENGINE.compile_file(path) {
+            Ok(ast) => ast,
+            Err(err) => {
+                log::error!("Failed to compile script {}: {}", name, err);
+
+                continue;
+            }
+        };
+
+        if !ast
+            .iter_functions()
+            .any(|function| function.name == HANDLER_NAME)
+        {
+            log::warn!("Script {} has no {} function", name, HANDLER_NAME);
+
+            continue;
+        }
+
+        scripts.push(Script { name, ast });
+    }
+
+    log::info!("Loaded {} input scripts", scripts.len());
+
+    *SCRIPTS.lock().unwrap() = scripts;
+}
+
+fn track_keys(payload: &Value) {
+    let (Some(kind), Some(key)) = (
+        payload.get("kind").and_then(Value::as_str),
+        payload.get("value").and_then(Value::as_str),
+    ) else {
+        return;
+    };
+
+    let mut pressed_keys = PRESSED_KEYS.lock().unwrap();
+
+    match kind {
+        "KeyboardPress" => {
+            pressed_keys.insert(key.to_string());
+        }
+        "KeyboardRelease" => {
+            pressed_keys.remove(key);
+        }
+        _ => {}
+    }
+}
+
+fn run_action<R: Runtime>(app_handle: &AppHandle<R>, action: ScriptAction) {
+    let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+
+    match action {
+        ScriptAction::Motion { group, index } => {
+            control::dispatch(app_handle, ControlCommand::Motion { group, index })
+        }
+        ScriptAction::Expression(index) => {
+            control::dispatch(app_handle, ControlCommand::Expression(index))
+        }
+        ScriptAction::Parameter { id, value } => {
+            let _ =
+                app_handle.emit_to(target, "set-parameter", json!({ "id": id, "value": value }));
+        }
+        ScriptAction::Message(text) => {
+            let _ = app_handle.emit_to(target, "show-message", text);
+        }
+    }
+}
+
+fn run_scripts<R: Runtime>(app_handle: &AppHandle<R>, event: &str, payload: Value) {
+    let scripts = SCRIPTS.lock().unwrap();
+
+    if scripts.is_empty() {
+        return;
+    }
+
+    let Some(mut input) = rhai::serde::to_dynamic(&payload)
+        .ok()
+        .and_then(|input| input.try_cast::<Map>())
+    else {
+        return;
+    };
+
+    let pressed: Vec<Dynamic> = PRESSED_KEYS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|key| Dynamic::from(key.clone()))
+        .collect();
+
+    input.insert("source".into(), Dynamic::from(event.to_string()));
+    input.insert("pressed".into(), Dynamic::from_array(pressed));
+
+    for script in scripts.iter() {
+        let result = ENGINE.call_fn_with_options::<Dynamic>(
+            CallFnOptions::new().eval_ast(false),
+            &mut Scope::new(),
+            &script.ast,
+            HANDLER_NAME,
+            (Dynamic::from_map(input.clone()),),
+        );
+
+        if let Err(err) = result {
+            log::error!("Script {} failed: {}", script.name, err);
+        }
+    }
+
+    drop(scripts);
+
+    let actions = std::mem::take(&mut *ACTIONS.lock().unwrap());
+
+    for action in actions {
+        run_action(app_handle, action);
+    }
+}
+
+// Scripts run on their own thread so a slow one never stalls the input listeners
+pub fn handle_input<S: Serialize>(event: &str, payload: &S) {
+    let Ok(payload) = serde_json::to_value(payload) else {
+        return;
+    };
+
+    // Keys are tracked here so dropped events never leave a key stuck as pressed
+    track_keys(&payload);
+
+    // Mouse movement fires far too often to run scripts on
+    if payload.get("kind").and_then(Value::as_str) == Some("MouseMove") {
+        return;
+    }
+
+    if let Some(sender) = EVENTS.lock().unwrap().as_ref() {
+        // Events are dropped while the scripts are too far behind
+        let _ = sender.try_send((event.to_string(), payload));
+    }
+}
+
+fn spawn_runner<R: Runtime>(app_handle: &AppHandle<R>) {
+    let (sender, receiver) = mpsc::sync_channel::<(String, Value)>(EVENT_QUEUE_SIZE);
+
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || {
+        for (event, payload) in receiver {
+            run_scripts(&app_handle, &event, payload);
+        }
+    });
+
+    *EVENTS.lock().unwrap() = Some(sender);
+}
+
+pub fn init<R: Runtime>(app_handle: &AppHandle<R>) -> Result<(), String> {
+    let dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|err| err.to_string())?
+        .join(SCRIPTS_DIR);
+
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+
+    load_scripts(&dir);
+
+    spawn_runner(app_handle);
+
+    let scripts_dir = dir.clone();
+
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |result: DebounceEventResult| {
+        if result.is_ok() {
+            load_scripts(&scripts_dir);
+        }
+    })
+    .map_err(|err| err.to_string())?;
+
+    debouncer
+        .watcher()
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|err| err.to_string())?;
+
+    *WATCHER.lock().unwrap() = Some(debouncer);
+
+    Ok(())
+}
@@ -0,0 +1,273 @@
+use super::{
+    control::{self, ControlCommand},
+    overlay,
+};
+use axum::{
+    Json, Router,
+    extract::{
+        Path, Query, Request, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{any, post},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{LazyLock, Mutex},
+};
+use tauri::{
+    AppHandle,
+    async_runtime::{self, JoinHandle},
+    command,
+};
+use tokio::{
+    net::TcpListener,
+    sync::broadcast::{self, error::RecvError},
+};
+
+#[derive(Debug, Clone, Serialize)]
+struct ServerEvent {
+    event: String,
+    payload: Value,
+}
+
+#[derive(Clone)]
+pub(crate) struct ServerState {
+    pub app_handle: AppHandle,
+    pub token: String,
+}
+
+#[derive(Deserialize)]
+struct TokenQuery {
+    token: Option<String>,
+}
+
+// Indexes count from 0, the same as the command line, behavior rules and scripts
+#[derive(Deserialize)]
+struct MotionBody {
+    group: String,
+    index: usize,
+}
+
+#[derive(Deserialize)]
+struct ExpressionBody {
+    index: usize,
+}
+
+#[derive(Deserialize)]
+struct MirrorBody {
+    enabled: bool,
+}
+
+static EVENTS: LazyLock<broadcast::Sender<ServerEvent>> =
+    LazyLock::new(|| broadcast::channel(256).0);
+
+static SERVER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+pub fn publish<S: Serialize>(event: &str, payload: &S) {
+    if EVENTS.receiver_count() == 0 {
+        return;
+    }
+
+    let Ok(payload) = serde_json::to_value(payload) else {
+        return;
+    };
+
+    let _ = EVENTS.send(ServerEvent {
+        event: event.to_string(),
+        payload,
+    });
+}
+
+// Compares every byte so the time taken doesn't reveal how much of the token matched
+fn token_matches(token: &str, expected: &str) -> bool {
+    let (token, expected) = (token.as_bytes(), expected.as_bytes());
+
+    if token.len() != expected.len() {
+        return false;
+    }
+
+    token
+        .iter()
+        .zip(expected)
+        .fold(0, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+async fn authorize(
+    State(state): State<ServerState>,
+    Query(query): Query<TokenQuery>,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Response {
+    // Browsers cannot set headers on WebSocket requests, so the token may also come as a query parameter
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or(query.token.as_deref());
+
+    if !token.is_some_and(|token| token_matches(token, &state.token)) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    next.run(request).await
+}
+
+pub(crate) fn require_token(
+    router: Router<ServerState>,
+    state: &ServerState,
+) -> Router<ServerState> {
+    router.route_layer(middleware::from_fn_with_state(state.clone(), authorize))
+}
+
+fn dispatch(state: &ServerState, command: ControlCommand) -> StatusCode {
+    control::dispatch(&state.app_handle, command);
+
+    StatusCode::NO_CONTENT
+}
+
+async fn show(State(state): State<ServerState>) -> StatusCode {
+    dispatch(&state, ControlCommand::Show)
+}
+
+async fn hide(State(state): State<ServerState>) -> StatusCode {
+    dispatch(&state, ControlCommand::Hide)
+}
+
+async fn toggle(State(state): State<ServerState>) -> StatusCode {
+    dispatch(&state, ControlCommand::Toggle)
+}
+
+async fn model(State(state): State<ServerState>, Path(id): Path<String>) -> StatusCode {
+    dispatch(&state, ControlCommand::Model(id))
+}
+
+async fn motion(State(state): State<ServerState>, Json(body): Json<MotionBody>) -> StatusCode {
+    dispatch(
+        &state,
+        ControlCommand::Motion {
+            group: body.group,
+            index: body.index,
+        },
+    )
+}
+
+async fn expression(
+    State(state): State<ServerState>,
+    Json(body): Json<ExpressionBody>,
+) -> StatusCode {
+    dispatch(&state, ControlCommand::Expression(body.index))
+}
+
+async fn mirror(State(state): State<ServerState>, Json(body): Json<MirrorBody>) -> StatusCode {
+    dispatch(&state, ControlCommand::Mirror(body.enabled))
+}
+
+async fn events(ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(stream_events)
+}
+
+async fn stream_events(mut socket: WebSocket) {
+    let mut receiver = EVENTS.subscribe();
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(event) => {
+                    let Ok(text) = serde_json::to_string(&event) else {
+                        continue;
+                    };
+
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_)) | Err(_)) | None => return,
+                _ => {}
+            },
+        }
+    }
+}
+
+/// Every route needs the token:
+///
+/// - `POST /api/show`, `/api/hide`, `/api/toggle`
+/// - `POST /api/model/{id}`
+/// - `POST /api/motion` with `{ "group": "Idle", "index": 0 }`, the index counting from 0
+/// - `POST /api/expression` with `{ "index": 0 }`, the index counting from 0
+/// - `POST /api/mirror` with `{ "enabled": true }`
+/// - `GET /api/events` upgrades to a WebSocket streaming input events
+fn api_router(state: &ServerState) -> Router<ServerState> {
+    let router = Router::new()
+        .route("/api/show", post(show))
+        .route("/api/hide", post(hide))
+        .route("/api/toggle", post(toggle))
+        .route("/api/model/{id}", post(model))
+        .route("/api/motion", post(motion))
+        .route("/api/expression", post(expression))
+        .route("/api/mirror", post(mirror))
+        .route("/api/events", any(events));
+
+    require_token(router, state)
+}
+
+async fn stop_server() {
+    let handle = SERVER.lock().unwrap().take();
+
+    if let Some(handle) = handle {
+        handle.abort();
+
+        let _ = handle.await;
+    }
+}
+
+#[command]
+pub async fn start_control_server(
+    app_handle: AppHandle,
+    port: u16,
+    token: String,
+    overlay: bool,
+) -> Result<(), String> {
+    if token.is_empty() {
+        return Err(String::from("Token must not be empty"));
+    }
+
+    stop_server().await;
+
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let state = ServerState { app_handle, token };
+
+    let mut router = api_router(&state);
+
+    if overlay {
+        router = router.merge(overlay::router(&state));
+    }
+
+    let router = router.with_state(state);
+
+    let handle = async_runtime::spawn(async move {
+        let _ = axum::serve(listener, router).await;
+    });
+
+    *SERVER.lock().unwrap() = Some(handle);
+
+    Ok(())
+}
+
+#[command]
+pub async fn stop_control_server() {
+    stop_server().await;
+}
@@ -0,0 +1,409 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{LazyLock, RwLock},
+};
+use tauri::{AppHandle, Manager, Runtime, command};
+use tauri_plugin_pinia::ManagerExt;
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+pub const SETTINGS_VERSION: u64 = 1;
+const LANGUAGES: [&str; 5] = ["en-US", "pt-BR", "vi-VN", "zh-CN", "zh-TW"];
+const CAT_STORE_FILE: &str = "cat.json";
+const GENERAL_STORE_FILE: &str = "general.json";
+
+// Fields older releases kept at the top level of the stores, and the groups they moved into
+const LEGACY_CAT_FIELDS: [(&str, &str, &str); 6] = [
+    ("mirrorMode", "model", "mirror"),
+    ("mouseMirror", "model", "mouseMirror"),
+    ("penetrable", "window", "passThrough"),
+    ("alwaysOnTop", "window", "alwaysOnTop"),
+    ("scale", "window", "scale"),
+    ("opacity", "window", "opacity"),
+];
+const LEGACY_GENERAL_FIELDS: [(&str, &str, &str); 5] = [
+    ("autoCheckUpdate", "update", "autoCheck"),
+    ("autostart", "app", "autostart"),
+    ("taskbarVisibility", "app", "taskbarVisible"),
+    ("theme", "appearance", "theme"),
+    ("isDark", "appearance", "isDark"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WindowSettings {
+    pub visible: bool,
+    pub pass_through: bool,
+    pub always_on_top: bool,
+    pub desktop_widget: bool,
+    pub scale: u32,
+    pub opacity: u32,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            pass_through: false,
+            always_on_top: false,
+            desktop_widget: false,
+            scale: 100,
+            opacity: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModelSettings {
+    pub mirror: bool,
+    pub mouse_mirror: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GeneralSettings {
+    pub language: Option<String>,
+    pub tray_visible: bool,
+    pub taskbar_visible: bool,
+}
+
+impl Default for GeneralSettings {
+    fn default() -> Self {
+        Self {
+            language: None,
+            tray_visible: true,
+            taskbar_visible: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PrivacySettings {
+    pub enabled: bool,
+}
+
+// A read-only copy of the pinia stores for the backend, which keep owning the values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Settings {
+    pub version: u64,
+    pub window: WindowSettings,
+    pub model: ModelSettings,
+    pub general: GeneralSettings,
+    pub privacy: PrivacySettings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            window: WindowSettings::default(),
+            model: ModelSettings::default(),
+            general: GeneralSettings::default(),
+            privacy: PrivacySettings::default(),
+        }
+    }
+}
+
+impl Settings {
+    fn validate(&self) -> Result<(), String> {
+        if !(1..=500).contains(&self.window.scale) {
+            return Err(format!("Invalid window scale: {}", self.window.scale));
+        }
+
+        if !(10..=100).contains(&self.window.opacity) {
+            return Err(format!("Invalid window opacity: {}", self.window.opacity));
+        }
+
+        if let Some(language) = &self.general.language {
+            if !LANGUAGES.contains(&language.as_str()) {
+                return Err(format!("Unsupported language: {}", language));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+static SETTINGS: LazyLock<RwLock<Settings>> = LazyLock::new(|| RwLock::new(Settings::default()));
+
+type Migration = fn(&mut Map<String, Value>, &Path) -> Result<(), String>;
+
+// Entry `n` upgrades settings of version `n` to version `n + 1`
+const MIGRATIONS: [Migration; SETTINGS_VERSION as usize] = [migrate_v0];
+
+fn read_store(path: &Path) -> Result<Option<Map<String, Value>>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+    match serde_json::from_str(&content).map_err(|err| err.to_string())? {
+        Value::Object(store) => Ok(Some(store)),
+        _ => Err(format!("Invalid store file: {}", path.display())),
+    }
+}
+
+fn write_store(path: &Path, store: Map<String, Value>) -> Result<(), String> {
+    let content = serde_json::to_vec(&Value::Object(store)).map_err(|err| err.to_string())?;
+
+    fs::write(path, content).map_err(|err| err.to_string())
+}
+
+// Moves the legacy fields into their groups, unless an older frontend already copied them there
+fn absorb_legacy_fields(store: &mut Map<String, Value>, fields: &[(&str, &str, &str)]) {
+    let migrated = store
+        .remove("migrated")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false);
+
+    for (legacy, group, key) in fields {
+        let Some(value) = store.remove(*legacy) else {
+            continue;
+        };
+
+        if migrated {
+            continue;
+        }
+
+        let group = store
+            .entry(group.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if let Some(group) = group.as_object_mut() {
+            group.insert(key.to_string(), value);
+        }
+    }
+}
+
+fn copy_fields(
+    settings: &mut Map<String, Value>,
+    store: &Map<String, Value>,
+    fields: &[(&str, &str, &str, &str)],
+) {
+    for (store_group, store_key, group, key) in fields {
+        let Some(value) = store
+            .get(*store_group)
+            .and_then(|store_group| store_group.get(*store_key))
+        else {
+            continue;
+        };
+
+        let group = settings
+            .entry(group.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if let Some(group) = group.as_object_mut() {
+            group
+                .entry(key.to_string())
+                .or_insert_with(|| value.clone());
+        }
+    }
+}
+
+// Version 0 is everything before the settings file existed, when the stores were the only copy
+fn migrate_v0(settings: &mut Map<String, Value>, stores_dir: &Path) -> Result<(), String> {
+    let cat_path = stores_dir.join(CAT_STORE_FILE);
+
+    if let Some(mut cat) = read_store(&cat_path)? {
+        absorb_legacy_fields(&mut cat, &LEGACY_CAT_FIELDS);
+
+        copy_fields(
+            settings,
+            &cat,
+            &[
+                ("window", "visible", "window", "visible"),
+                ("window", "passThrough", "window", "passThrough"),
+                ("window", "alwaysOnTop", "window", "alwaysOnTop"),
+                ("window", "desktopWidget", "window", "desktopWidget"),
+                ("window", "scale", "window", "scale"),
+                ("window", "opacity", "window", "opacity"),
+                ("model", "mirror", "model", "mirror"),
+                ("model", "mouseMirror", "model", "mouseMirror"),
+            ],
+        );
+
+        write_store(&cat_path, cat)?;
+    }
+
+    let general_path = stores_dir.join(GENERAL_STORE_FILE);
+
+    if let Some(mut general) = read_store(&general_path)? {
+        absorb_legacy_fields(&mut general, &LEGACY_GENERAL_FIELDS);
+
+        copy_fields(
+            settings,
+            &general,
+            &[
+                ("appearance", "language", "general", "language"),
+                ("app", "trayVisible", "general", "trayVisible"),
+                ("app", "taskbarVisible", "general", "taskbarVisible"),
+                ("privacy", "enabled", "privacy", "enabled"),
+            ],
+        );
+
+        write_store(&general_path, general)?;
+    }
+
+    Ok(())
+}
+
+fn migrate<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    settings: &mut Map<String, Value>,
+) -> Result<(), String> {
+    let version = settings.get("version").and_then(Value::as_u64).unwrap_or(0);
+
+    let stores_dir = app_handle.pinia().path();
+
+    for migration in MIGRATIONS.iter().skip(version as usize) {
+        migration(settings, &stores_dir)?;
+    }
+
+    Ok(())
+}
+
+fn parse(value: Value) -> Result<Settings, String> {
+    let Value::Object(mut settings) = value else {
+        return Err(String::from("Settings must be a JSON object"));
+    };
+
+    let version = settings
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(SETTINGS_VERSION);
+
+    if version > SETTINGS_VERSION {
+        return Err(format!("Unsupported settings version: {}", version));
+    }
+
+    settings.insert(String::from("version"), Value::from(SETTINGS_VERSION));
+
+    let settings: Settings =
+        serde_json::from_value(Value::Object(settings)).map_err(|err| err.to_string())?;
+
+    settings.validate()?;
+
+    Ok(settings)
+}
+
+fn settings_path<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf, String> {
+    let config_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|err| err.to_string())?;
+
+    Ok(config_dir.join(SETTINGS_FILE_NAME))
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension("json.bak")
+}
+
+fn read_settings(path: &Path) -> Result<Map<String, Value>, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+    let Value::Object(settings) = serde_json::from_str(&content).map_err(|err| err.to_string())?
+    else {
+        return Err(String::from("Settings must be a JSON object"));
+    };
+
+    parse(Value::Object(settings.clone()))?;
+
+    Ok(settings)
+}
+
+fn write_settings(path: &Path, settings: &Settings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+
+    let content = serde_json::to_vec_pretty(settings).map_err(|err| err.to_string())?;
+
+    // Write to a temporary file first so a crash never leaves a truncated settings file behind
+    let temp_path = path.with_extension("json.tmp");
+
+    let mut file = File::create(&temp_path).map_err(|err| err.to_string())?;
+
+    file.write_all(&content).map_err(|err| err.to_string())?;
+
+    file.sync_all().map_err(|err| err.to_string())?;
+
+    // Only keep a backup of a file that is still readable
+    if read_settings(path).is_ok() {
+        fs::copy(path, backup_path(path)).map_err(|err| err.to_string())?;
+    }
+
+    fs::rename(&temp_path, path).map_err(|err| err.to_string())
+}
+
+pub fn load<R: Runtime>(app_handle: &AppHandle<R>) {
+    let Ok(path) = settings_path(app_handle) else {
+        return;
+    };
+
+    // A missing file counts as version 0, and a corrupted or invalid one is replaced by the last good copy
+    let (mut settings, mut outdated) = if path.exists() {
+        match read_settings(&path) {
+            Ok(settings) => (settings, false),
+            Err(_) => match read_settings(&backup_path(&path)) {
+                Ok(settings) => (settings, true),
+                Err(_) => return,
+            },
+        }
+    } else {
+        (Map::new(), false)
+    };
+
+    let version = settings.get("version").and_then(Value::as_u64).unwrap_or(0);
+
+    if version < SETTINGS_VERSION {
+        match migrate(app_handle, &mut settings) {
+            Ok(()) => outdated = true,
+            Err(err) => log::error!("Failed to migrate settings: {}", err),
+        }
+    }
+
+    let Ok(settings) = parse(Value::Object(settings)) else {
+        return;
+    };
+
+    if outdated {
+        let _ = write_settings(&path, &settings);
+    }
+
+    *SETTINGS.write().unwrap() = settings;
+}
+
+pub fn privacy_enabled() -> bool {
+    SETTINGS.read().unwrap().privacy.enabled
+}
+
+pub fn current() -> Settings {
+    SETTINGS.read().unwrap().clone()
+}
+
+#[command]
+pub async fn get_settings() -> Settings {
+    current()
+}
+
+#[command]
+pub async fn save_settings<R: Runtime>(
+    app_handle: AppHandle<R>,
+    settings: Value,
+) -> Result<(), String> {
+    let settings = parse(settings)?;
+
+    write_settings(&settings_path(&app_handle)?, &settings)?;
+
+    *SETTINGS.write().unwrap() = settings;
+
+    Ok(())
+}
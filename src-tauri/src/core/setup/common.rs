@@ -6,3 +6,5 @@ pub fn platform(
     _preference_window: WebviewWindow,
 ) {
 }
+
+pub fn cat_window(_window: &WebviewWindow) {}
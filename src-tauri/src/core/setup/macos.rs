@@ -1,7 +1,6 @@
 #![allow(deprecated)]
 use tauri::{AppHandle, Emitter, EventTarget, Manager, WebviewWindow};
 use tauri_nspanel::{CollectionBehavior, PanelLevel, StyleMask, WebviewWindowExt, tauri_panel};
-use tauri_plugin_custom_window::MAIN_WINDOW_LABEL;
 
 const WINDOW_FOCUS_EVENT: &str = "tauri://focus";
 const WINDOW_BLUR_EVENT: &str = "tauri://blur";
@@ -34,7 +33,11 @@ pub fn platform(
 
     let _ = app_handle.set_dock_visibility(false);
 
-    let panel = main_window.to_panel::<NsPanel>().unwrap();
+    cat_window(&main_window);
+}
+
+pub fn cat_window(window: &WebviewWindow) {
+    let panel = window.to_panel::<NsPanel>().unwrap();
 
     panel.set_level(PanelLevel::Dock.value());
 
@@ -50,40 +53,40 @@ pub fn platform(
 
     let handler = NsPanelEventHandler::new();
 
-    let focus_window = main_window.clone();
+    let focus_window = window.clone();
     handler.window_did_become_key(move |_| {
-        let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+        let target = EventTarget::labeled(focus_window.label());
 
         let _ = focus_window.emit_to(target, WINDOW_FOCUS_EVENT, true);
     });
 
-    let blur_window = main_window.clone();
+    let blur_window = window.clone();
     handler.window_did_resign_key(move |_| {
-        let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+        let target = EventTarget::labeled(blur_window.label());
 
         let _ = blur_window.emit_to(target, WINDOW_BLUR_EVENT, true);
     });
 
     fn emit_position(window: &WebviewWindow) {
-        let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+        let target = EventTarget::labeled(window.label());
 
         if let Ok(position) = window.outer_position() {
             let _ = window.emit_to(target, WINDOW_MOVED_EVENT, position);
         }
     }
 
-    let resize_window = main_window.clone();
+    let resize_window = window.clone();
     handler.window_did_resize(move |_| {
         emit_position(&resize_window);
 
-        let target = EventTarget::labeled(MAIN_WINDOW_LABEL);
+        let target = EventTarget::labeled(resize_window.label());
 
         if let Ok(size) = resize_window.inner_size() {
             let _ = resize_window.emit_to(target, WINDOW_RESIZED_EVENT, size);
         }
     });
 
-    let move_window = main_window.clone();
+    let move_window = window.clone();
     handler.window_did_move(move |_| {
         emit_position(&move_window);
     });
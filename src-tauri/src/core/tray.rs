@@ -0,0 +1,437 @@
+use super::{
+    control::{self, ControlCommand},
+    settings,
+};
+use serde::Deserialize;
+use std::sync::{LazyLock, Mutex};
+use tauri::{
+    AppHandle, Emitter, Manager, WebviewWindow, Wry,
+    async_runtime::spawn,
+    command,
+    image::Image,
+    menu::{CheckMenuItem, IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
+    path::BaseDirectory,
+    tray::TrayIconBuilder,
+};
+use tauri_plugin_custom_window::{
+    hide_window, is_cat_window, is_desktop_widget, set_always_on_top, set_click_through,
+    show_preference_window, show_window,
+};
+use tauri_plugin_opener::OpenerExt;
+
+const TRAY_ID: &str = "BONGO_CAT_TRAY";
+const GITHUB_LINK: &str = "https://github.com/ayangweb/BongoCat";
+const MODEL_ID_PREFIX: &str = "model:";
+const SCALE_ID_PREFIX: &str = "scale:";
+const OPACITY_ID_PREFIX: &str = "opacity:";
+const SCALE_OPTIONS: [u32; 5] = [50, 75, 100, 125, 150];
+const OPACITY_OPTIONS: [u32; 4] = [25, 50, 75, 100];
+
+#[cfg(target_os = "macos")]
+const TRAY_ICON: &str = "assets/tray-mac.png";
+
+#[cfg(not(target_os = "macos"))]
+const TRAY_ICON: &str = "assets/tray.png";
+
+#[derive(Clone, Copy)]
+enum Label {
+    Preference,
+    ShowCat,
+    HideCat,
+    PassThrough,
+    AlwaysOnTop,
+    Model,
+    CheckUpdate,
+    OpenSource,
+    RestartApp,
+    QuitApp,
+    Standard,
+    Keyboard,
+    Gamepad,
+    WindowSize,
+    Opacity,
+}
+
+impl Label {
+    // Keys of the labels build.rs reads from the locale files
+    fn key(self) -> &'static str {
+        match self {
+            Self::Preference => "preference",
+            Self::ShowCat => "showCat",
+            Self::HideCat => "hideCat",
+            Self::PassThrough => "passThrough",
+            Self::AlwaysOnTop => "alwaysOnTop",
+            Self::Model => "model",
+            Self::CheckUpdate => "checkUpdate",
+            Self::OpenSource => "openSource",
+            Self::RestartApp => "restartApp",
+            Self::QuitApp => "quitApp",
+            Self::Standard => "standard",
+            Self::Keyboard => "keyboard",
+            Self::Gamepad => "gamepad",
+            Self::WindowSize => "windowSize",
+            Self::Opacity => "opacity",
+        }
+    }
+}
+
+// Generated by build.rs from the tray labels in src/locales
+include!(concat!(env!("OUT_DIR"), "/tray_texts.rs"));
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrayModel {
+    id: String,
+    mode: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TraySettings {
+    language: String,
+    tray_visible: bool,
+    visible: bool,
+    pass_through: bool,
+    always_on_top: bool,
+    scale: u32,
+    opacity: u32,
+    models: Vec<TrayModel>,
+    current_model_id: Option<String>,
+}
+
+impl Default for TraySettings {
+    fn default() -> Self {
+        let settings = settings::current();
+
+        Self {
+            language: settings
+                .general
+                .language
+                .unwrap_or_else(|| String::from("en-US")),
+            tray_visible: settings.general.tray_visible,
+            visible: settings.window.visible,
+            pass_through: settings.window.pass_through,
+            always_on_top: settings.window.always_on_top,
+            scale: settings.window.scale,
+            opacity: settings.window.opacity,
+            models: vec![],
+            current_model_id: None,
+        }
+    }
+}
+
+static SETTINGS: LazyLock<Mutex<TraySettings>> =
+    LazyLock::new(|| Mutex::new(TraySettings::default()));
+
+fn translate(settings: &TraySettings, label: Label) -> &'static str {
+    let key = label.key();
+
+    bundled_text(&settings.language, key)
+        .or_else(|| bundled_text("en-US", key))
+        .unwrap_or(key)
+}
+
+fn mode_label(mode: &str) -> Label {
+    match mode {
+        "keyboard" => Label::Keyboard,
+        "gamepad" => Label::Gamepad,
+        _ => Label::Standard,
+    }
+}
+
+fn percent_items(
+    app_handle: &AppHandle,
+    prefix: &str,
+    options: &[u32],
+    current: u32,
+) -> tauri::Result<Vec<CheckMenuItem<Wry>>> {
+    let mut items = vec![];
+
+    // A value set in the preferences that isn't one of the options is shown, but can't be picked
+    if !options.contains(&current) {
+        items.push(CheckMenuItem::new(
+            app_handle,
+            format!("{}%", current),
+            false,
+            true,
+            None::<&str>,
+        )?);
+    }
+
+    for option in options {
+        items.push(CheckMenuItem::with_id(
+            app_handle,
+            format!("{}{}", prefix, option),
+            format!("{}%", option),
+            true,
+            *option == current,
+            None::<&str>,
+        )?);
+    }
+
+    Ok(items)
+}
+
+fn as_menu_items(items: &[CheckMenuItem<Wry>]) -> Vec<&dyn IsMenuItem<Wry>> {
+    items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<Wry>)
+        .collect()
+}
+
+fn build_menu(app_handle: &AppHandle, settings: &TraySettings) -> tauri::Result<Menu<Wry>> {
+    let text = |label| translate(settings, label);
+
+    let model_items = settings
+        .models
+        .iter()
+        .enumerate()
+        .map(|(index, model)| {
+            CheckMenuItem::with_id(
+                app_handle,
+                format!("{}{}", MODEL_ID_PREFIX, model.id),
+                format!("{}. {}", index + 1, text(mode_label(&model.mode))),
+                true,
+                settings.current_model_id.as_ref() == Some(&model.id),
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+
+    let scale_items = percent_items(app_handle, SCALE_ID_PREFIX, &SCALE_OPTIONS, settings.scale)?;
+
+    let opacity_items = percent_items(
+        app_handle,
+        OPACITY_ID_PREFIX,
+        &OPACITY_OPTIONS,
+        settings.opacity,
+    )?;
+
+    let visible_label = if settings.visible {
+        Label::HideCat
+    } else {
+        Label::ShowCat
+    };
+
+    let package_info = app_handle.package_info();
+
+    Menu::with_items(
+        app_handle,
+        &[
+            &MenuItem::with_id(
+                app_handle,
+                "preference",
+                text(Label::Preference),
+                true,
+                None::<&str>,
+            )?,
+            &MenuItem::with_id(
+                app_handle,
+                "visible",
+                text(visible_label),
+                true,
+                None::<&str>,
+            )?,
+            &CheckMenuItem::with_id(
+                app_handle,
+                "pass-through",
+                text(Label::PassThrough),
+                true,
+                settings.pass_through,
+                None::<&str>,
+            )?,
+            &CheckMenuItem::with_id(
+                app_handle,
+                "always-on-top",
+                text(Label::AlwaysOnTop),
+                true,
+                settings.always_on_top,
+                None::<&str>,
+            )?,
+            &Submenu::with_items(
+                app_handle,
+                text(Label::WindowSize),
+                true,
+                &as_menu_items(&scale_items),
+            )?,
+            &Submenu::with_items(
+                app_handle,
+                text(Label::Opacity),
+                true,
+                &as_menu_items(&opacity_items),
+            )?,
+            &Submenu::with_items(
+                app_handle,
+                text(Label::Model),
+                !model_items.is_empty(),
+                &as_menu_items(&model_items),
+            )?,
+            &PredefinedMenuItem::separator(app_handle)?,
+            &MenuItem::with_id(
+                app_handle,
+                "check-update",
+                text(Label::CheckUpdate),
+                true,
+                None::<&str>,
+            )?,
+            &MenuItem::with_id(
+                app_handle,
+                "open-source",
+                text(Label::OpenSource),
+                true,
+                None::<&str>,
+            )?,
+            &PredefinedMenuItem::separator(app_handle)?,
+            &MenuItem::new(
+                app_handle,
+                format!("v{}", package_info.version),
+                false,
+                None::<&str>,
+            )?,
+            &MenuItem::with_id(
+                app_handle,
+                "restart",
+                text(Label::RestartApp),
+                true,
+                None::<&str>,
+            )?,
+            &MenuItem::with_id(app_handle, "quit", text(Label::QuitApp), true, None::<&str>)?,
+        ],
+    )
+}
+
+fn apply_to_cat_windows<F, Fut>(app_handle: &AppHandle, apply: F)
+where
+    F: Fn(AppHandle, WebviewWindow) -> Fut + Send + 'static,
+    Fut: Future<Output = tauri_plugin_custom_window::Result<()>> + Send,
+{
+    let app_handle = app_handle.clone();
+
+    spawn(async move {
+        let windows = app_handle
+            .webview_windows()
+            .into_values()
+            .filter(|window| is_cat_window(window.label()));
+
+        for window in windows {
+            let _ = apply(app_handle.clone(), window).await;
+        }
+    });
+}
+
+fn handle_menu_event(app_handle: &AppHandle, event: MenuEvent) {
+    let mut settings = SETTINGS.lock().unwrap();
+
+    // Window changes apply right away so the tray works even while the main webview is busy,
+    // the dispatched command then brings the stores in line.
+    match event.id().as_ref() {
+        "preference" => show_preference_window(app_handle),
+        "visible" => {
+            let visible = !settings.visible;
+
+            settings.visible = visible;
+
+            apply_to_cat_windows(app_handle, move |app_handle, window| async move {
+                if visible {
+                    show_window(app_handle, window).await
+                } else {
+                    hide_window(app_handle, window).await
+                }
+            });
+
+            let command = if visible {
+                ControlCommand::Show
+            } else {
+                ControlCommand::Hide
+            };
+
+            control::dispatch(app_handle, command);
+        }
+        "pass-through" => {
+            let pass_through = !settings.pass_through;
+
+            settings.pass_through = pass_through;
+
+            apply_to_cat_windows(app_handle, move |app_handle, window| {
+                set_click_through(app_handle, window, pass_through)
+            });
+
+            control::dispatch(app_handle, ControlCommand::PassThrough(pass_through));
+        }
+        "always-on-top" => {
+            let always_on_top = !settings.always_on_top;
+
+            settings.always_on_top = always_on_top;
+
+            apply_to_cat_windows(app_handle, move |app_handle, window| async move {
+                // Desktop widgets keep their own layer until the mode is turned off
+                if is_desktop_widget(&app_handle, window.label()) {
+                    return Ok(());
+                }
+
+                set_always_on_top(app_handle, window, always_on_top).await
+            });
+
+            control::dispatch(app_handle, ControlCommand::AlwaysOnTop(always_on_top));
+        }
+        "check-update" => {
+            show_preference_window(app_handle);
+
+            let _ = app_handle.emit("update-app", ());
+        }
+        "open-source" => {
+            let _ = app_handle.opener().open_url(GITHUB_LINK, None::<&str>);
+        }
+        "restart" => app_handle.restart(),
+        "quit" => app_handle.exit(0),
+        // The model, size and opacity depend on the loaded model, so only the main window can apply them
+        id => {
+            if let Some(model_id) = id.strip_prefix(MODEL_ID_PREFIX) {
+                control::dispatch(app_handle, ControlCommand::Model(model_id.to_string()));
+            } else if let Some(Ok(scale)) = id.strip_prefix(SCALE_ID_PREFIX).map(str::parse) {
+                control::dispatch(app_handle, ControlCommand::Scale(scale));
+            } else if let Some(Ok(opacity)) = id.strip_prefix(OPACITY_ID_PREFIX).map(str::parse) {
+                control::dispatch(app_handle, ControlCommand::Opacity(opacity));
+            }
+        }
+    }
+}
+
+pub fn create(app_handle: &AppHandle) -> tauri::Result<()> {
+    let settings = SETTINGS.lock().unwrap().clone();
+
+    let icon_path = app_handle
+        .path()
+        .resolve(TRAY_ICON, BaseDirectory::Resource)?;
+
+    let package_info = app_handle.package_info();
+
+    TrayIconBuilder::with_id(TRAY_ID)
+        .icon(Image::from_path(icon_path)?)
+        .icon_as_template(true)
+        .show_menu_on_left_click(true)
+        .tooltip(format!("{} v{}", package_info.name, package_info.version))
+        .menu(&build_menu(app_handle, &settings)?)
+        .on_menu_event(handle_menu_event)
+        .build(app_handle)?;
+
+    Ok(())
+}
+
+#[command]
+pub async fn update_tray(app_handle: AppHandle, settings: TraySettings) -> Result<(), String> {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return Err(String::from("Tray icon has not been created"));
+    };
+
+    let menu = build_menu(&app_handle, &settings).map_err(|err| err.to_string())?;
+
+    tray.set_menu(Some(menu)).map_err(|err| err.to_string())?;
+
+    tray.set_visible(settings.tray_visible)
+        .map_err(|err| err.to_string())?;
+
+    *SETTINGS.lock().unwrap() = settings;
+
+    Ok(())
+}
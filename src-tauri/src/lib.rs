@@ -2,19 +2,40 @@ mod core;
 mod utils;
 
 use core::{
+    atlas::pack_key_atlas,
+    backup::{self, export_backup, inspect_backup, restore_backup},
+    cat_window::{close_cat_window, create_cat_window, set_input_source},
+    cli::{self, run_pending_command},
+    control,
+    cover::generate_model_cover,
     device::start_device_listening,
     gamepad::{start_gamepad_listing, stop_gamepad_listing},
-    prevent_default, setup,
+    hot_reload::{start_model_watching, stop_model_watching},
+    idle::{self, get_idle_duration, set_idle_threshold},
+    model::{
+        collect_custom_models, delete_custom_model, duplicate_custom_model, list_custom_models,
+        rename_custom_model,
+    },
+    overlay::set_overlay_scene,
+    prevent_default, rules, script,
+    server::{start_control_server, stop_control_server},
+    settings::{self, get_settings, save_settings},
+    setup,
+    tray::{self, update_tray},
 };
 use tauri::{Manager, WindowEvent, generate_handler};
 use tauri_plugin_autostart::MacosLauncher;
 use tauri_plugin_custom_window::{
     MAIN_WINDOW_LABEL, PREFERENCE_WINDOW_LABEL, show_preference_window,
 };
-use utils::fs_extra::copy_dir;
+use utils::fs_extra::{cancel_copy_dir, copy_dir};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Reject malformed commands before the single instance plugin forwards them, and keep
+    // a valid one around in case this turns out to be the first instance
+    cli::check_env();
+
     let app = tauri::Builder::default()
         .setup(|app| {
             let app_handle = app.handle();
@@ -23,15 +44,58 @@ pub fn run() {
 
             let preference_window = app.get_webview_window(PREFERENCE_WINDOW_LABEL).unwrap();
 
+            if let Err(err) = backup::apply_pending_restore(&app_handle) {
+                log::error!("Failed to apply the pending backup restore: {}", err);
+            }
+
+            settings::load(&app_handle);
+
             setup::default(&app_handle, main_window.clone(), preference_window.clone());
 
+            tray::create(&app_handle)?;
+
+            idle::init(&app_handle);
+
+            if let Err(err) = script::init(&app_handle) {
+                log::error!("Failed to start input scripts: {}", err);
+            }
+
+            if let Err(err) = rules::init(&app_handle) {
+                log::error!("Failed to start behavior rules: {}", err);
+            }
+
             Ok(())
         })
         .invoke_handler(generate_handler![
             copy_dir,
+            cancel_copy_dir,
             start_device_listening,
             start_gamepad_listing,
-            stop_gamepad_listing
+            stop_gamepad_listing,
+            list_custom_models,
+            delete_custom_model,
+            duplicate_custom_model,
+            rename_custom_model,
+            collect_custom_models,
+            start_model_watching,
+            stop_model_watching,
+            generate_model_cover,
+            pack_key_atlas,
+            create_cat_window,
+            close_cat_window,
+            set_input_source,
+            start_control_server,
+            stop_control_server,
+            set_overlay_scene,
+            update_tray,
+            get_settings,
+            save_settings,
+            export_backup,
+            inspect_backup,
+            restore_backup,
+            set_idle_threshold,
+            get_idle_duration,
+            run_pending_command
         ])
         .plugin(tauri_plugin_admin_status::init())
         .plugin(tauri_plugin_custom_window::init())
@@ -42,8 +106,9 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(prevent_default::init())
         .plugin(tauri_plugin_single_instance::init(
-            |app_handle, _argv, _cwd| {
-                show_preference_window(app_handle);
+            |app_handle, argv, _cwd| match cli::parse(argv) {
+                Ok(Some(command)) => control::dispatch(app_handle, command),
+                _ => show_preference_window(app_handle),
             },
         ))
         .plugin(
@@ -3,6 +3,17 @@ const COMMANDS: &[&str] = &[
     "hide_window",
     "set_always_on_top",
     "set_taskbar_visibility",
+    "snap_window",
+    "dock_window",
+    "set_follow_monitor",
+    "restore_window_position",
+    "set_input_shape",
+    "set_fullscreen_watch",
+    "set_click_through",
+    "get_window_state",
+    "set_overlay_mode",
+    "set_desktop_widget",
+    "set_focus_watch",
 ];
 
 fn main() {
@@ -0,0 +1,112 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Manager, Runtime, command};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+struct FocusChangedEvent {
+    classes: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct FocusState {
+    enabled: AtomicBool,
+    running: AtomicBool,
+}
+
+#[cfg(target_os = "linux")]
+mod watcher {
+    use super::{FocusChangedEvent, FocusState};
+    use crate::{
+        commands::MAIN_WINDOW_LABEL,
+        x11::{self, X11Connection, X11Result},
+    };
+    use std::{sync::atomic::Ordering, thread, time::Duration};
+    use tauri::{AppHandle, Emitter, EventTarget, Manager, Runtime};
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    fn focused_classes<R: Runtime>(
+        app_handle: &AppHandle<R>,
+        x11: &X11Connection,
+    ) -> X11Result<Option<Vec<String>>> {
+        let Some(window) = x11.active_window()? else {
+            return Ok(Some(vec![]));
+        };
+
+        // Focusing one of our own windows keeps the previous application's profile
+        let is_own_window = app_handle
+            .webview_windows()
+            .values()
+            .any(|webview_window| x11::window_id(webview_window) == Some(window));
+
+        if is_own_window {
+            return Ok(None);
+        }
+
+        Ok(Some(x11.wm_class(window)?))
+    }
+
+    pub fn watch<R: Runtime>(app_handle: AppHandle<R>) {
+        let state = app_handle.state::<FocusState>();
+
+        let Ok(x11) = X11Connection::connect() else {
+            state.running.store(false, Ordering::SeqCst);
+
+            return;
+        };
+
+        let mut current: Option<Vec<String>> = None;
+
+        while state.enabled.load(Ordering::SeqCst) {
+            thread::sleep(POLL_INTERVAL);
+
+            let Ok(Some(classes)) = focused_classes(&app_handle, &x11) else {
+                continue;
+            };
+
+            if current.as_ref() == Some(&classes) {
+                continue;
+            }
+
+            current = Some(classes.clone());
+
+            let _ = app_handle.emit_to(
+                EventTarget::labeled(MAIN_WINDOW_LABEL),
+                "focus-changed",
+                FocusChangedEvent { classes },
+            );
+        }
+
+        state.running.store(false, Ordering::SeqCst);
+    }
+}
+
+#[command]
+pub async fn set_focus_watch<R: Runtime>(app_handle: AppHandle<R>, enabled: bool) {
+    let state = app_handle.state::<FocusState>();
+
+    state.enabled.store(enabled, Ordering::SeqCst);
+
+    if !enabled {
+        return;
+    }
+
+    let Ok(_) = state
+        .running
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+    else {
+        return;
+    };
+
+    #[cfg(target_os = "linux")]
+    {
+        let app_handle = app_handle.clone();
+
+        std::thread::spawn(move || watcher::watch(app_handle));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    state.running.store(false, Ordering::SeqCst);
+}
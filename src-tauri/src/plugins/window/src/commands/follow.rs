@@ -0,0 +1,258 @@
+use super::snap::window_monitor;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Manager, Monitor, PhysicalPosition, Runtime, WebviewWindow, command};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FollowSource {
+    Cursor,
+    FocusedWindow,
+}
+
+#[derive(Clone)]
+struct FollowConfig {
+    source: FollowSource,
+    delay: Duration,
+    pending: Option<(PhysicalPosition<i32>, Instant)>,
+}
+
+#[derive(Default)]
+pub struct FollowState {
+    windows: Mutex<HashMap<String, FollowConfig>>,
+    running: AtomicBool,
+}
+
+fn is_same_monitor(a: &Monitor, b: &Monitor) -> bool {
+    a.position() == b.position() && a.size() == b.size()
+}
+
+// Keeps one X connection for the lifetime of the follow thread instead of one per poll
+#[derive(Default)]
+struct FocusTracker {
+    #[cfg(target_os = "linux")]
+    x11: Option<crate::x11::X11Connection>,
+}
+
+impl FocusTracker {
+    #[cfg(target_os = "linux")]
+    fn focused_window_monitor<R: Runtime>(&mut self, app_handle: &AppHandle<R>) -> Option<Monitor> {
+        use crate::x11::X11Connection;
+
+        if self.x11.is_none() {
+            self.x11 = X11Connection::connect().ok();
+        }
+
+        let x11 = self.x11.as_ref()?;
+
+        let rect = x11
+            .active_window()
+            .and_then(|window| window.map(|window| x11.window_rect(window)).transpose());
+
+        let (position, size) = match rect {
+            Ok(rect) => rect?,
+            Err(_) => {
+                // Reconnect on the next poll in case the X server connection was lost
+                self.x11 = None;
+
+                return None;
+            }
+        };
+
+        let center_x = position.x as f64 + size.width as f64 / 2.0;
+        let center_y = position.y as f64 + size.height as f64 / 2.0;
+
+        app_handle.monitor_from_point(center_x, center_y).ok()?
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn focused_window_monitor<R: Runtime>(
+        &mut self,
+        _app_handle: &AppHandle<R>,
+    ) -> Option<Monitor> {
+        None
+    }
+}
+
+fn cursor_monitor<R: Runtime>(app_handle: &AppHandle<R>) -> Option<Monitor> {
+    let cursor = app_handle.cursor_position().ok()?;
+
+    app_handle.monitor_from_point(cursor.x, cursor.y).ok()?
+}
+
+fn relative_axis(position: i32, size: u32, start: i32, length: u32) -> f64 {
+    let range = length as f64 - size as f64;
+
+    if range <= 0.0 {
+        return 0.0;
+    }
+
+    ((position - start) as f64 / range).clamp(0.0, 1.0)
+}
+
+fn move_to_monitor<R: Runtime>(
+    window: &WebviewWindow<R>,
+    current: &Monitor,
+    target: &Monitor,
+) -> tauri::Result<()> {
+    let position = window.outer_position()?;
+    let size = window.outer_size()?;
+
+    let from = current.work_area();
+    let to = target.work_area();
+
+    let ratio_x = relative_axis(position.x, size.width, from.position.x, from.size.width);
+    let ratio_y = relative_axis(position.y, size.height, from.position.y, from.size.height);
+
+    let range_x = (to.size.width as f64 - size.width as f64).max(0.0);
+    let range_y = (to.size.height as f64 - size.height as f64).max(0.0);
+
+    let x = to.position.x + (range_x * ratio_x).round() as i32;
+    let y = to.position.y + (range_y * ratio_y).round() as i32;
+
+    window.set_position(PhysicalPosition::new(x, y))
+}
+
+fn follow_window<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    config: &mut FollowConfig,
+    focused: Option<&Monitor>,
+) {
+    let Some(window) = app_handle.get_webview_window(label) else {
+        return;
+    };
+
+    if !window.is_visible().unwrap_or_default() {
+        return;
+    }
+
+    let target = match config.source {
+        FollowSource::Cursor => cursor_monitor(app_handle),
+        FollowSource::FocusedWindow => focused.cloned().or_else(|| cursor_monitor(app_handle)),
+    };
+
+    let (Some(current), Some(target)) = (window_monitor(&window), target) else {
+        return;
+    };
+
+    if is_same_monitor(&current, &target) {
+        config.pending = None;
+
+        return;
+    }
+
+    let target_position = *target.position();
+
+    match config.pending {
+        Some((position, since)) if position == target_position => {
+            if since.elapsed() < config.delay {
+                return;
+            }
+        }
+        _ => {
+            config.pending = Some((target_position, Instant::now()));
+
+            if !config.delay.is_zero() {
+                return;
+            }
+        }
+    }
+
+    config.pending = None;
+
+    let _ = move_to_monitor(&window, &current, &target);
+}
+
+fn follow_loop<R: Runtime>(app_handle: AppHandle<R>) {
+    let state = app_handle.state::<FollowState>();
+
+    let mut tracker = FocusTracker::default();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        // Work on a snapshot so window APIs are never called while holding the lock
+        let snapshot: Vec<(String, FollowConfig)> = {
+            let windows = state.windows.lock().unwrap();
+
+            if windows.is_empty() {
+                state.running.store(false, Ordering::SeqCst);
+
+                return;
+            }
+
+            windows
+                .iter()
+                .map(|(label, config)| (label.clone(), config.clone()))
+                .collect()
+        };
+
+        let focused = snapshot
+            .iter()
+            .any(|(_, config)| config.source == FollowSource::FocusedWindow)
+            .then(|| tracker.focused_window_monitor(&app_handle))
+            .flatten();
+
+        for (label, mut config) in snapshot {
+            follow_window(&app_handle, &label, &mut config, focused.as_ref());
+
+            if let Some(current) = state.windows.lock().unwrap().get_mut(&label) {
+                current.pending = config.pending;
+            }
+        }
+    }
+}
+
+#[command]
+pub async fn set_follow_monitor<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    enabled: bool,
+    source: FollowSource,
+    delay: u64,
+) {
+    let state = app_handle.state::<FollowState>();
+
+    {
+        let mut windows = state.windows.lock().unwrap();
+
+        if enabled {
+            windows.insert(
+                window.label().to_string(),
+                FollowConfig {
+                    source,
+                    delay: Duration::from_millis(delay),
+                    pending: None,
+                },
+            );
+        } else {
+            windows.remove(window.label());
+        }
+    }
+
+    if !enabled {
+        return;
+    }
+
+    let Ok(_) = state
+        .running
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+    else {
+        return;
+    };
+
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || follow_loop(app_handle));
+}
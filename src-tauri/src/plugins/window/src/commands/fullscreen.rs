@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{
+    Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+use tauri::{AppHandle, Manager, Runtime, command};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FullscreenAction {
+    Hide,
+    Lower,
+}
+
+#[derive(Clone)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+struct FullscreenConfig {
+    action: FullscreenAction,
+    allowlist: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct FullscreenState {
+    config: Mutex<Option<FullscreenConfig>>,
+    running: AtomicBool,
+}
+
+#[cfg(target_os = "linux")]
+mod watcher {
+    use super::{FullscreenAction, FullscreenState};
+    use crate::{
+        commands::{
+            is_cat_window,
+            linux::apply_always_on_top,
+            state::{record_lowered, recorded_always_on_top},
+        },
+        x11::{X11Connection, X11Result},
+    };
+    use std::{sync::atomic::Ordering, thread, time::Duration};
+    use tauri::{AppHandle, Manager, Runtime};
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    fn fullscreen_class(x11: &X11Connection) -> X11Result<Option<Vec<String>>> {
+        let Some(window) = x11.active_window()? else {
+            return Ok(None);
+        };
+
+        if !x11.is_fullscreen(window)? {
+            return Ok(None);
+        }
+
+        Ok(Some(x11.wm_class(window)?))
+    }
+
+    fn is_allowed(classes: &[String], allowlist: &[String]) -> bool {
+        classes.iter().any(|class| {
+            allowlist
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(class))
+        })
+    }
+
+    fn cover<R: Runtime>(app_handle: &AppHandle<R>, action: FullscreenAction) -> Vec<String> {
+        let mut affected = vec![];
+
+        for (label, window) in app_handle.webview_windows() {
+            if !is_cat_window(&label) {
+                continue;
+            }
+
+            match action {
+                FullscreenAction::Hide => {
+                    if !window.is_visible().unwrap_or_default() {
+                        continue;
+                    }
+
+                    let _ = window.hide();
+                }
+                FullscreenAction::Lower => {
+                    let always_on_top = recorded_always_on_top(app_handle, &label)
+                        .unwrap_or_else(|| window.is_always_on_top().unwrap_or_default());
+
+                    if !always_on_top {
+                        continue;
+                    }
+
+                    if apply_always_on_top(&window, false).is_err() {
+                        continue;
+                    }
+
+                    record_lowered(app_handle, &label, true);
+                }
+            }
+
+            affected.push(label);
+        }
+
+        affected
+    }
+
+    fn uncover<R: Runtime>(app_handle: &AppHandle<R>, action: FullscreenAction, labels: &[String]) {
+        for label in labels {
+            let Some(window) = app_handle.get_webview_window(label) else {
+                continue;
+            };
+
+            match action {
+                FullscreenAction::Hide => {
+                    let _ = window.show();
+                }
+                FullscreenAction::Lower => {
+                    // Restore the layer the user chose, which lowering never overwrites
+                    let always_on_top = recorded_always_on_top(app_handle, label).unwrap_or(true);
+
+                    let _ = apply_always_on_top(&window, always_on_top);
+
+                    record_lowered(app_handle, label, false);
+                }
+            }
+        }
+    }
+
+    pub fn watch<R: Runtime>(app_handle: AppHandle<R>) {
+        let state = app_handle.state::<FullscreenState>();
+
+        let Ok(x11) = X11Connection::connect() else {
+            state.running.store(false, Ordering::SeqCst);
+
+            return;
+        };
+
+        let mut covered: Option<(FullscreenAction, Vec<String>)> = None;
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let config = {
+                let config = state.config.lock().unwrap();
+
+                if config.is_none() && covered.is_none() {
+                    state.running.store(false, Ordering::SeqCst);
+
+                    return;
+                }
+
+                config.clone()
+            };
+
+            let covering_action = config
+                .filter(|config| {
+                    fullscreen_class(&x11)
+                        .ok()
+                        .flatten()
+                        .is_some_and(|classes| !is_allowed(&classes, &config.allowlist))
+                })
+                .map(|config| config.action);
+
+            match (covering_action, covered.take()) {
+                (Some(action), None) => {
+                    covered = Some((action, cover(&app_handle, action)));
+                }
+                (None, Some((action, labels))) => {
+                    uncover(&app_handle, action, &labels);
+                }
+                (_, previous) => covered = previous,
+            }
+        }
+    }
+}
+
+#[command]
+pub async fn set_fullscreen_watch<R: Runtime>(
+    app_handle: AppHandle<R>,
+    enabled: bool,
+    action: FullscreenAction,
+    allowlist: Vec<String>,
+) {
+    let state = app_handle.state::<FullscreenState>();
+
+    *state.config.lock().unwrap() = enabled.then_some(FullscreenConfig { action, allowlist });
+
+    if !enabled {
+        return;
+    }
+
+    let Ok(_) = state
+        .running
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+    else {
+        return;
+    };
+
+    #[cfg(target_os = "linux")]
+    {
+        let app_handle = app_handle.clone();
+
+        std::thread::spawn(move || watcher::watch(app_handle));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    state.running.store(false, Ordering::SeqCst);
+}
@@ -0,0 +1,281 @@
+use super::snap::redock;
+use crate::Result;
+use std::{
+    collections::HashMap,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Manager, PhysicalPosition, Runtime, WebviewWindow, command};
+
+const LAYOUT_FILE_NAME: &str = "window-layouts.json";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+type LayoutPositions = HashMap<String, HashMap<String, PhysicalPosition<i32>>>;
+
+#[derive(Default)]
+pub struct LayoutState {
+    fingerprint: Mutex<Option<String>>,
+    positions: Mutex<LayoutPositions>,
+    pending: Mutex<HashMap<String, PhysicalPosition<i32>>>,
+    last_moved: Mutex<Option<Instant>>,
+    saving: AtomicBool,
+}
+
+fn layout_path<R: Runtime>(app_handle: &AppHandle<R>) -> Option<PathBuf> {
+    let config_dir = app_handle.path().app_config_dir().ok()?;
+
+    Some(config_dir.join(LAYOUT_FILE_NAME))
+}
+
+fn load_positions<R: Runtime>(app_handle: &AppHandle<R>) -> LayoutPositions {
+    let Some(path) = layout_path(app_handle) else {
+        return LayoutPositions::default();
+    };
+
+    read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_positions<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    positions: &LayoutPositions,
+) -> Result<()> {
+    let Some(path) = layout_path(app_handle) else {
+        return Ok(());
+    };
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let content = serde_json::to_string(positions)?;
+
+    write(path, content)?;
+
+    Ok(())
+}
+
+fn layout_fingerprint<R: Runtime>(app_handle: &AppHandle<R>) -> Option<String> {
+    let monitors = app_handle.available_monitors().ok()?;
+
+    if monitors.is_empty() {
+        return None;
+    }
+
+    let mut parts: Vec<String> = monitors
+        .iter()
+        .map(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+
+            format!(
+                "{}:{}x{}@{},{}*{}",
+                monitor.name().map(String::as_str).unwrap_or_default(),
+                size.width,
+                size.height,
+                position.x,
+                position.y,
+                monitor.scale_factor()
+            )
+        })
+        .collect();
+
+    parts.sort();
+
+    Some(parts.join("|"))
+}
+
+fn restore_position<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    fingerprint: &str,
+) -> Result<bool> {
+    let position = {
+        let state = app_handle.state::<LayoutState>();
+        let positions = state.positions.lock().unwrap();
+
+        positions
+            .get(fingerprint)
+            .and_then(|windows| windows.get(window.label()))
+            .copied()
+    };
+
+    let Some(position) = position else {
+        return Ok(false);
+    };
+
+    window.set_position(position)?;
+
+    Ok(true)
+}
+
+fn watch_layout<R: Runtime>(app_handle: AppHandle<R>) {
+    let state = app_handle.state::<LayoutState>();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let Some(fingerprint) = layout_fingerprint(&app_handle) else {
+            continue;
+        };
+
+        {
+            let mut current = state.fingerprint.lock().unwrap();
+
+            if current.as_deref() == Some(fingerprint.as_str()) {
+                continue;
+            }
+
+            *current = Some(fingerprint.clone());
+        }
+
+        // Docked windows follow their anchor onto the new work area, even if they weren't resized
+        for window in app_handle.webview_windows().into_values() {
+            let _ = restore_position(&app_handle, &window, &fingerprint);
+
+            redock(&app_handle, window.label());
+        }
+    }
+}
+
+pub(crate) fn init_layout<R: Runtime>(app_handle: &AppHandle<R>) {
+    let state = LayoutState {
+        fingerprint: Mutex::new(layout_fingerprint(app_handle)),
+        positions: Mutex::new(load_positions(app_handle)),
+        ..Default::default()
+    };
+
+    app_handle.manage(state);
+
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || watch_layout(app_handle));
+}
+
+fn flush_positions<R: Runtime>(app_handle: &AppHandle<R>) {
+    let state = app_handle.state::<LayoutState>();
+
+    let pending = std::mem::take(&mut *state.pending.lock().unwrap());
+
+    if pending.is_empty() {
+        return;
+    }
+
+    let Some(fingerprint) = layout_fingerprint(app_handle) else {
+        return;
+    };
+
+    // The layout is still settling, the moves belong to the watcher's restore.
+    if state.fingerprint.lock().unwrap().as_deref() != Some(fingerprint.as_str()) {
+        return;
+    }
+
+    let mut positions = state.positions.lock().unwrap();
+
+    let windows = positions.entry(fingerprint).or_default();
+
+    let mut changed = false;
+
+    for (label, position) in pending {
+        if windows.insert(label, position) != Some(position) {
+            changed = true;
+        }
+    }
+
+    if changed {
+        let _ = save_positions(app_handle, &positions);
+    }
+}
+
+fn save_loop<R: Runtime>(app_handle: AppHandle<R>) {
+    let state = app_handle.state::<LayoutState>();
+
+    loop {
+        thread::sleep(SAVE_DELAY);
+
+        let settled = state
+            .last_moved
+            .lock()
+            .unwrap()
+            .is_none_or(|last_moved| last_moved.elapsed() >= SAVE_DELAY);
+
+        if !settled {
+            continue;
+        }
+
+        flush_positions(&app_handle);
+
+        state.saving.store(false, Ordering::SeqCst);
+
+        // A move may have landed between the flush and clearing the flag
+        let has_pending = !state.pending.lock().unwrap().is_empty();
+
+        if !has_pending
+            || state
+                .saving
+                .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+        {
+            return;
+        }
+    }
+}
+
+pub(crate) fn remember_position<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    position: PhysicalPosition<i32>,
+) {
+    let is_minimized = app_handle
+        .get_webview_window(label)
+        .and_then(|window| window.is_minimized().ok())
+        .unwrap_or_default();
+
+    if is_minimized {
+        return;
+    }
+
+    let state = app_handle.state::<LayoutState>();
+
+    // Dragging fires a move per frame, so only record it here and persist once the window settles
+    state
+        .pending
+        .lock()
+        .unwrap()
+        .insert(label.to_string(), position);
+
+    *state.last_moved.lock().unwrap() = Some(Instant::now());
+
+    if state
+        .saving
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return;
+    }
+
+    let app_handle = app_handle.clone();
+
+    thread::spawn(move || save_loop(app_handle));
+}
+
+#[command]
+pub async fn restore_window_position<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<bool> {
+    let Some(fingerprint) = layout_fingerprint(&app_handle) else {
+        return Ok(false);
+    };
+
+    restore_position(&app_handle, &window, &fingerprint)
+}
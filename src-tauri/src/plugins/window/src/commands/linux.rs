@@ -1,33 +1,102 @@
-use tauri::{AppHandle, Runtime, WebviewWindow, command};
+use super::state::{
+    is_desktop_widget, record_layer, record_taskbar_visibility, start_topmost, stop_topmost,
+};
+use super::widget::apply_desktop_widget;
+use crate::{
+    Error, Result,
+    x11::{self, X11State},
+};
+use std::sync::atomic::Ordering;
+use std::thread;
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow, command};
 
 #[command]
-pub async fn show_window<R: Runtime>(_app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    let _ = window.show();
-    let _ = window.unminimize();
-    let _ = window.set_focus();
-}
+pub async fn show_window<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<()> {
+    window.show()?;
+    window.unminimize()?;
+    window.set_focus()?;
 
-#[command]
-pub async fn hide_window<R: Runtime>(_app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    let _ = window.hide();
+    // Mapping the window again resets the pager and below states set by the desktop widget mode.
+    if is_desktop_widget(&app_handle, window.label()) {
+        apply_desktop_widget(&window, true)?;
+    }
+
+    Ok(())
 }
 
 #[command]
-pub async fn set_always_on_top<R: Runtime>(
+pub async fn hide_window<R: Runtime>(
     _app_handle: AppHandle<R>,
     window: WebviewWindow<R>,
+) -> Result<()> {
+    window.hide()?;
+
+    Ok(())
+}
+
+pub(crate) fn apply_always_on_top<R: Runtime>(
+    window: &WebviewWindow<R>,
     always_on_top: bool,
-) {
+) -> Result<()> {
+    let app_handle = window.app_handle();
+
+    let xid = x11::window_id(window);
+
     if always_on_top {
-        let _ = window.set_always_on_bottom(false);
-        let _ = window.set_always_on_top(true);
+        window.set_always_on_bottom(false)?;
+        window.set_always_on_top(true)?;
+
+        let Some(xid) = xid else { return Ok(()) };
+
+        let Some(running) = start_topmost(app_handle, window.label()) else {
+            return Ok(());
+        };
+
+        thread::spawn(move || {
+            let _ = x11::keep_above(xid, &running);
+
+            running.store(false, Ordering::SeqCst);
+        });
     } else {
-        let _ = window.set_always_on_top(false);
-        let _ = window.set_always_on_bottom(true);
+        stop_topmost(app_handle, window.label());
+
+        if let Some(xid) = xid {
+            x11::set_above(&app_handle.state::<X11State>(), xid, false)
+                .map_err(|err| Error::Platform(err.to_string()))?;
+        }
+
+        window.set_always_on_top(false)?;
+        window.set_always_on_bottom(true)?;
     }
+
+    Ok(())
+}
+
+#[command]
+pub async fn set_always_on_top<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    always_on_top: bool,
+) -> Result<()> {
+    apply_always_on_top(&window, always_on_top)?;
+
+    record_layer(&app_handle, window.label(), always_on_top, !always_on_top);
+
+    Ok(())
 }
 
 #[command]
-pub async fn set_taskbar_visibility<R: Runtime>(window: WebviewWindow<R>, visible: bool) {
-    let _ = window.set_skip_taskbar(!visible);
+pub async fn set_taskbar_visibility<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    visible: bool,
+) -> Result<()> {
+    window.set_skip_taskbar(!visible)?;
+
+    record_taskbar_visibility(&app_handle, window.label(), visible);
+
+    Ok(())
 }
@@ -1,5 +1,6 @@
 #![allow(deprecated)]
-use crate::MAIN_WINDOW_LABEL;
+use super::state::{record_layer, record_taskbar_visibility};
+use crate::{Result, is_cat_window};
 use tauri::{AppHandle, Runtime, WebviewWindow, command};
 use tauri_nspanel::{CollectionBehavior, ManagerExt, PanelLevel};
 
@@ -9,20 +10,17 @@ enum MacOSPanelStatus {
     SetAlwaysOnTop(bool),
 }
 
-fn is_main_window<R: Runtime>(window: &WebviewWindow<R>) -> bool {
-    window.label() == MAIN_WINDOW_LABEL
-}
-
 fn set_macos_panel<R: Runtime>(
     app_handle: &AppHandle<R>,
     window: &WebviewWindow<R>,
     status: MacOSPanelStatus,
-) {
-    if is_main_window(window) {
+) -> Result<()> {
+    if is_cat_window(window.label()) {
         let app_handle_clone = app_handle.clone();
+        let label = window.label().to_string();
 
-        let _ = app_handle.run_on_main_thread(move || {
-            if let Ok(panel) = app_handle_clone.get_webview_panel(MAIN_WINDOW_LABEL) {
+        app_handle.run_on_main_thread(move || {
+            if let Ok(panel) = app_handle_clone.get_webview_panel(&label) {
                 match status {
                     MacOSPanelStatus::Show => {
                         panel.show();
@@ -55,28 +53,40 @@ fn set_macos_panel<R: Runtime>(
                     }
                 }
             }
-        });
+        })?;
     }
+
+    Ok(())
 }
 
 #[command]
-pub async fn show_window<R: Runtime>(app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    if is_main_window(&window) {
-        set_macos_panel(&app_handle, &window, MacOSPanelStatus::Show);
-    } else {
-        let _ = window.show();
-        let _ = window.unminimize();
-        let _ = window.set_focus();
+pub async fn show_window<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<()> {
+    if is_cat_window(window.label()) {
+        return set_macos_panel(&app_handle, &window, MacOSPanelStatus::Show);
     }
+
+    window.show()?;
+    window.unminimize()?;
+    window.set_focus()?;
+
+    Ok(())
 }
 
 #[command]
-pub async fn hide_window<R: Runtime>(app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    if is_main_window(&window) {
-        set_macos_panel(&app_handle, &window, MacOSPanelStatus::Hide);
-    } else {
-        let _ = window.hide();
+pub async fn hide_window<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<()> {
+    if is_cat_window(window.label()) {
+        return set_macos_panel(&app_handle, &window, MacOSPanelStatus::Hide);
     }
+
+    window.hide()?;
+
+    Ok(())
 }
 
 #[command]
@@ -84,25 +94,35 @@ pub async fn set_always_on_top<R: Runtime>(
     app_handle: AppHandle<R>,
     window: WebviewWindow<R>,
     always_on_top: bool,
-) {
-    if is_main_window(&window) {
-        return set_macos_panel(
+) -> Result<()> {
+    if is_cat_window(window.label()) {
+        set_macos_panel(
             &app_handle,
             &window,
             MacOSPanelStatus::SetAlwaysOnTop(always_on_top),
-        );
-    }
-
-    if always_on_top {
-        let _ = window.set_always_on_bottom(false);
-        let _ = window.set_always_on_top(true);
+        )?;
+    } else if always_on_top {
+        window.set_always_on_bottom(false)?;
+        window.set_always_on_top(true)?;
     } else {
-        let _ = window.set_always_on_top(false);
-        let _ = window.set_always_on_bottom(true);
+        window.set_always_on_top(false)?;
+        window.set_always_on_bottom(true)?;
     }
+
+    record_layer(&app_handle, window.label(), always_on_top, !always_on_top);
+
+    Ok(())
 }
 
 #[command]
-pub async fn set_taskbar_visibility<R: Runtime>(app_handle: AppHandle<R>, visible: bool) {
-    let _ = app_handle.set_dock_visibility(visible);
+pub async fn set_taskbar_visibility<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    visible: bool,
+) -> Result<()> {
+    app_handle.set_dock_visibility(visible)?;
+
+    record_taskbar_visibility(&app_handle, window.label(), visible);
+
+    Ok(())
 }
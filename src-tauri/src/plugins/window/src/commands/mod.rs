@@ -2,6 +2,17 @@ use tauri::{AppHandle, Manager, async_runtime::spawn};
 
 pub static MAIN_WINDOW_LABEL: &str = "main";
 pub static PREFERENCE_WINDOW_LABEL: &str = "preference";
+pub static CAT_WINDOW_PREFIX: &str = "cat-";
+
+mod focus;
+mod follow;
+mod fullscreen;
+mod layout;
+mod overlay;
+mod shape;
+mod snap;
+mod state;
+mod widget;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -12,6 +23,16 @@ mod windows;
 #[cfg(target_os = "linux")]
 mod linux;
 
+pub use focus::*;
+pub use follow::*;
+pub use fullscreen::*;
+pub use layout::*;
+pub use overlay::*;
+pub use shape::*;
+pub use snap::*;
+pub use state::*;
+pub use widget::*;
+
 #[cfg(target_os = "macos")]
 pub use macos::*;
 
@@ -21,6 +42,10 @@ pub use windows::*;
 #[cfg(target_os = "linux")]
 pub use linux::*;
 
+pub fn is_cat_window(label: &str) -> bool {
+    label == MAIN_WINDOW_LABEL || label.starts_with(CAT_WINDOW_PREFIX)
+}
+
 pub fn show_main_window(app_handle: &AppHandle) {
     show_window_by_label(app_handle, MAIN_WINDOW_LABEL);
 }
@@ -37,6 +62,6 @@ fn show_window_by_label(app_handle: &AppHandle, label: &str) {
     let app_handle = app_handle.clone();
 
     spawn(async move {
-        show_window(app_handle, window).await;
+        let _ = show_window(app_handle, window).await;
     });
 }
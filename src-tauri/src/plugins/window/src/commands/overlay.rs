@@ -0,0 +1,114 @@
+use super::DockAnchor;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use tauri::{Runtime, WebviewWindow, command};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverlayLayer {
+    Overlay,
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    not(all(target_os = "linux", feature = "layer-shell")),
+    allow(dead_code)
+)]
+pub struct OverlayConfig {
+    layer: OverlayLayer,
+    anchor: DockAnchor,
+    margin: i32,
+}
+
+#[cfg(all(target_os = "linux", feature = "layer-shell"))]
+mod layer_shell {
+    use super::{DockAnchor, OverlayConfig, OverlayLayer};
+    use gtk::prelude::*;
+    use gtk_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
+
+    const NAMESPACE: &str = "bongo-cat";
+
+    fn edges(anchor: DockAnchor) -> &'static [Edge] {
+        match anchor {
+            DockAnchor::TopLeft => &[Edge::Top, Edge::Left],
+            DockAnchor::Top => &[Edge::Top],
+            DockAnchor::TopRight => &[Edge::Top, Edge::Right],
+            DockAnchor::Left => &[Edge::Left],
+            DockAnchor::Right => &[Edge::Right],
+            DockAnchor::BottomLeft => &[Edge::Bottom, Edge::Left],
+            DockAnchor::Bottom => &[Edge::Bottom],
+            DockAnchor::BottomRight => &[Edge::Bottom, Edge::Right],
+        }
+    }
+
+    pub fn apply(window: &gtk::ApplicationWindow, config: Option<OverlayConfig>) -> bool {
+        if !gtk_layer_shell::is_supported() {
+            return false;
+        }
+
+        // A surface can only become a layer surface before it is mapped, so remap the window once.
+        if config.is_some() && !window.is_layer_window() {
+            let visible = window.is_visible();
+
+            window.hide();
+            window.unrealize();
+            window.init_layer_shell();
+            window.set_namespace(NAMESPACE);
+            window.set_keyboard_mode(KeyboardMode::None);
+
+            if visible {
+                window.show();
+            }
+        }
+
+        if !window.is_layer_window() {
+            return true;
+        }
+
+        let anchored = config
+            .map(|config| edges(config.anchor))
+            .unwrap_or_default();
+
+        for edge in [Edge::Top, Edge::Right, Edge::Bottom, Edge::Left] {
+            let enabled = anchored.contains(&edge);
+            let margin = config.filter(|_| enabled).map_or(0, |config| config.margin);
+
+            window.set_anchor(edge, enabled);
+            window.set_layer_shell_margin(edge, margin);
+        }
+
+        // Layer surfaces cannot go back to being toplevels, so disabling keeps an unanchored top layer.
+        let layer = match config.map(|config| config.layer) {
+            Some(OverlayLayer::Overlay) => Layer::Overlay,
+            Some(OverlayLayer::Bottom) => Layer::Bottom,
+            None => Layer::Top,
+        };
+
+        window.set_layer(layer);
+
+        true
+    }
+}
+
+// Sync commands run on the main thread, which is where GTK has to be touched
+#[command]
+pub fn set_overlay_mode<R: Runtime>(
+    window: WebviewWindow<R>,
+    config: Option<OverlayConfig>,
+) -> Result<bool> {
+    #[cfg(all(target_os = "linux", feature = "layer-shell"))]
+    {
+        let gtk_window = window.gtk_window()?;
+
+        Ok(layer_shell::apply(&gtk_window, config))
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "layer-shell")))]
+    {
+        let _ = (window, config);
+
+        Ok(false)
+    }
+}
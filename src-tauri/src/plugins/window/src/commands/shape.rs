@@ -0,0 +1,144 @@
+use crate::{Error, Result};
+use serde::Deserialize;
+use tauri::{Runtime, WebviewWindow, command};
+
+const ALPHA_THRESHOLD: u8 = 16;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InputMask {
+    width: u32,
+    height: u32,
+    alpha: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) struct MaskRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+fn row_spans(row: &[u8]) -> Vec<(usize, usize)> {
+    let mut spans = vec![];
+    let mut start = None;
+
+    for (index, alpha) in row.iter().enumerate() {
+        match (start, *alpha >= ALPHA_THRESHOLD) {
+            (None, true) => start = Some(index),
+            (Some(begin), false) => {
+                spans.push((begin, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(begin) = start {
+        spans.push((begin, row.len()));
+    }
+
+    spans
+}
+
+impl InputMask {
+    fn rects(&self, width: u32, height: u32) -> Result<Vec<MaskRect>> {
+        let size = self.width.checked_mul(self.height);
+
+        if self.width == 0
+            || self.height == 0
+            || size.is_none_or(|size| self.alpha.len() != size as usize)
+        {
+            return Err(Error::InvalidInputMask);
+        }
+
+        let scale_x = width as f64 / self.width as f64;
+        let scale_y = height as f64 / self.height as f64;
+
+        let mut rects: Vec<MaskRect> = vec![];
+        let mut previous: Vec<((usize, usize), usize)> = vec![];
+
+        for (row_index, row) in self.alpha.chunks(self.width as usize).enumerate() {
+            let top = (row_index as f64 * scale_y).floor() as i32;
+            let bottom = ((row_index + 1) as f64 * scale_y).ceil() as i32;
+
+            let spans = row_spans(row);
+
+            // Rows with the same spans extend the rectangles above them.
+            if !spans.is_empty() && spans.iter().eq(previous.iter().map(|(span, _)| span)) {
+                for (_, rect_index) in &previous {
+                    let rect = &mut rects[*rect_index];
+
+                    rect.height = (bottom - rect.y) as u32;
+                }
+
+                continue;
+            }
+
+            previous.clear();
+
+            for (start, end) in spans {
+                let left = (start as f64 * scale_x).floor() as i32;
+                let right = (end as f64 * scale_x).ceil() as i32;
+
+                previous.push(((start, end), rects.len()));
+
+                rects.push(MaskRect {
+                    x: left,
+                    y: top,
+                    width: (right - left) as u32,
+                    height: (bottom - top) as u32,
+                });
+            }
+        }
+
+        Ok(rects)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_input_shape<R: Runtime>(
+    window: &WebviewWindow<R>,
+    rects: Option<Vec<MaskRect>>,
+) -> Result<()> {
+    use crate::x11::{self, X11State};
+    use tauri::Manager;
+
+    let Some(xid) = x11::window_id(window) else {
+        return Ok(());
+    };
+
+    let state = window.state::<X11State>();
+
+    x11::set_input_shape(&state, window.label(), xid, rects)
+        .map_err(|err| Error::Platform(err.to_string()))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn apply_input_shape<R: Runtime>(
+    _window: &WebviewWindow<R>,
+    _rects: Option<Vec<MaskRect>>,
+) -> Result<()> {
+    Ok(())
+}
+
+#[command]
+pub async fn set_input_shape<R: Runtime>(
+    window: WebviewWindow<R>,
+    mask: Option<InputMask>,
+) -> Result<()> {
+    let rects = match mask {
+        Some(mask) => {
+            let size = window.inner_size()?;
+
+            Some(mask.rects(size.width, size.height)?)
+        }
+        None => None,
+    };
+
+    // A fully transparent mask would leave the window unreachable, fall back to the full window.
+    let rects = rects.filter(|rects| !rects.is_empty());
+
+    apply_input_shape(&window, rects)
+}
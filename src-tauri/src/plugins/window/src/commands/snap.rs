@@ -0,0 +1,232 @@
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Mutex};
+use tauri::{
+    AppHandle, Manager, Monitor, PhysicalPosition, PhysicalRect, Runtime, WebviewWindow, command,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DockAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+#[derive(Default)]
+pub struct DockState(Mutex<HashMap<String, DockAnchor>>);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Edge {
+    Start,
+    End,
+}
+
+impl DockAnchor {
+    fn from_edges(horizontal: Option<Edge>, vertical: Option<Edge>) -> Option<Self> {
+        let anchor = match (horizontal, vertical) {
+            (Some(Edge::Start), Some(Edge::Start)) => Self::TopLeft,
+            (None, Some(Edge::Start)) => Self::Top,
+            (Some(Edge::End), Some(Edge::Start)) => Self::TopRight,
+            (Some(Edge::Start), None) => Self::Left,
+            (Some(Edge::End), None) => Self::Right,
+            (Some(Edge::Start), Some(Edge::End)) => Self::BottomLeft,
+            (None, Some(Edge::End)) => Self::Bottom,
+            (Some(Edge::End), Some(Edge::End)) => Self::BottomRight,
+            (None, None) => return None,
+        };
+
+        Some(anchor)
+    }
+
+    fn edges(self) -> (Option<Edge>, Option<Edge>) {
+        match self {
+            Self::TopLeft => (Some(Edge::Start), Some(Edge::Start)),
+            Self::Top => (None, Some(Edge::Start)),
+            Self::TopRight => (Some(Edge::End), Some(Edge::Start)),
+            Self::Left => (Some(Edge::Start), None),
+            Self::Right => (Some(Edge::End), None),
+            Self::BottomLeft => (Some(Edge::Start), Some(Edge::End)),
+            Self::Bottom => (None, Some(Edge::End)),
+            Self::BottomRight => (Some(Edge::End), Some(Edge::End)),
+        }
+    }
+}
+
+pub(crate) fn window_monitor<R: Runtime>(window: &WebviewWindow<R>) -> Option<Monitor> {
+    let position = window.outer_position().ok()?;
+    let size = window.outer_size().ok()?;
+
+    let center_x = position.x as f64 + size.width as f64 / 2.0;
+    let center_y = position.y as f64 + size.height as f64 / 2.0;
+
+    window
+        .monitor_from_point(center_x, center_y)
+        .ok()
+        .flatten()
+        .or_else(|| window.current_monitor().ok().flatten())
+}
+
+fn snap_axis(position: i32, size: u32, start: i32, length: u32, distance: u32) -> Option<Edge> {
+    if distance == 0 {
+        return None;
+    }
+
+    let end = start + length as i32;
+    let distance = distance as i32;
+
+    if (position - start).abs() <= distance {
+        Some(Edge::Start)
+    } else if (end - (position + size as i32)).abs() <= distance {
+        Some(Edge::End)
+    } else {
+        None
+    }
+}
+
+fn place_axis(position: i32, size: u32, start: i32, length: u32, edge: Option<Edge>) -> i32 {
+    match edge {
+        Some(Edge::Start) => start,
+        Some(Edge::End) => start + length as i32 - size as i32,
+        None => position,
+    }
+}
+
+fn clamp_axis(position: i32, size: u32, start: i32, length: u32) -> i32 {
+    let max = start + length as i32 - size as i32;
+
+    position.min(max).max(start)
+}
+
+pub(crate) fn work_area<R: Runtime>(window: &WebviewWindow<R>) -> Option<PhysicalRect<i32, u32>> {
+    window_monitor(window).map(|monitor| *monitor.work_area())
+}
+
+fn set_anchor<R: Runtime>(app_handle: &AppHandle<R>, label: &str, anchor: Option<DockAnchor>) {
+    let state = app_handle.state::<DockState>();
+    let mut anchors = state.0.lock().unwrap();
+
+    match anchor {
+        Some(anchor) => anchors.insert(label.to_string(), anchor),
+        None => anchors.remove(label),
+    };
+}
+
+fn move_to_edges<R: Runtime>(
+    window: &WebviewWindow<R>,
+    area: &PhysicalRect<i32, u32>,
+    (horizontal, vertical): (Option<Edge>, Option<Edge>),
+    keep_in_screen: bool,
+) -> Result<()> {
+    let position = window.outer_position()?;
+    let size = window.outer_size()?;
+
+    let mut x = place_axis(
+        position.x,
+        size.width,
+        area.position.x,
+        area.size.width,
+        horizontal,
+    );
+    let mut y = place_axis(
+        position.y,
+        size.height,
+        area.position.y,
+        area.size.height,
+        vertical,
+    );
+
+    if keep_in_screen {
+        x = clamp_axis(x, size.width, area.position.x, area.size.width);
+        y = clamp_axis(y, size.height, area.position.y, area.size.height);
+    }
+
+    if position == PhysicalPosition::new(x, y) {
+        return Ok(());
+    }
+
+    window.set_position(PhysicalPosition::new(x, y))?;
+
+    Ok(())
+}
+
+pub(crate) fn redock<R: Runtime>(app_handle: &AppHandle<R>, label: &str) {
+    let anchor = {
+        let state = app_handle.state::<DockState>();
+        let anchors = state.0.lock().unwrap();
+
+        anchors.get(label).copied()
+    };
+
+    let Some(anchor) = anchor else {
+        return;
+    };
+
+    let Some(window) = app_handle.get_webview_window(label) else {
+        return;
+    };
+
+    let Some(area) = work_area(&window) else {
+        return;
+    };
+
+    let _ = move_to_edges(&window, &area, anchor.edges(), true);
+}
+
+#[command]
+pub async fn snap_window<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    snap_distance: u32,
+    keep_in_screen: bool,
+) -> Result<Option<DockAnchor>> {
+    let Some(area) = work_area(&window) else {
+        return Ok(None);
+    };
+
+    let position = window.outer_position()?;
+    let size = window.outer_size()?;
+
+    let horizontal = snap_axis(
+        position.x,
+        size.width,
+        area.position.x,
+        area.size.width,
+        snap_distance,
+    );
+    let vertical = snap_axis(
+        position.y,
+        size.height,
+        area.position.y,
+        area.size.height,
+        snap_distance,
+    );
+
+    let anchor = DockAnchor::from_edges(horizontal, vertical);
+
+    set_anchor(&app_handle, window.label(), anchor);
+
+    move_to_edges(&window, &area, (horizontal, vertical), keep_in_screen)?;
+
+    Ok(anchor)
+}
+
+#[command]
+pub async fn dock_window<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    anchor: Option<DockAnchor>,
+) -> Result<()> {
+    set_anchor(&app_handle, window.label(), anchor);
+
+    let (Some(anchor), Some(area)) = (anchor, work_area(&window)) else {
+        return Ok(());
+    };
+
+    move_to_edges(&window, &area, anchor.edges(), true)
+}
@@ -0,0 +1,181 @@
+use crate::{Error, Result};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow, command};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct WindowFlags {
+    always_on_top: Option<bool>,
+    always_on_bottom: bool,
+    taskbar_visible: Option<bool>,
+    click_through: bool,
+    desktop_widget: bool,
+    // Temporarily below other windows while a fullscreen app is focused
+    lowered: bool,
+}
+
+#[derive(Default)]
+pub struct WindowFlagsState(Mutex<HashMap<String, WindowFlags>>);
+
+// Every keep-above thread owns its flag, so stopping one window never affects another
+#[derive(Default)]
+pub struct TopmostState(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowState {
+    label: String,
+    visible: bool,
+    always_on_top: bool,
+    always_on_bottom: bool,
+    taskbar_visible: Option<bool>,
+    click_through: bool,
+    desktop_widget: bool,
+}
+
+fn update_flags<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    update: impl FnOnce(&mut WindowFlags),
+) {
+    let state = app_handle.state::<WindowFlagsState>();
+    let mut flags = state.0.lock().unwrap();
+
+    update(flags.entry(label.to_string()).or_default());
+}
+
+pub(crate) fn record_layer<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    always_on_top: bool,
+    always_on_bottom: bool,
+) {
+    update_flags(app_handle, label, |flags| {
+        flags.always_on_top = Some(always_on_top);
+        flags.always_on_bottom = always_on_bottom;
+        flags.lowered = false;
+    });
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn record_lowered<R: Runtime>(app_handle: &AppHandle<R>, label: &str, lowered: bool) {
+    update_flags(app_handle, label, |flags| flags.lowered = lowered);
+}
+
+pub(crate) fn record_taskbar_visibility<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    visible: bool,
+) {
+    update_flags(app_handle, label, |flags| {
+        flags.taskbar_visible = Some(visible)
+    });
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn recorded_always_on_top<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+) -> Option<bool> {
+    let state = app_handle.state::<WindowFlagsState>();
+    let flags = state.0.lock().unwrap();
+
+    flags.get(label).and_then(|flags| flags.always_on_top)
+}
+
+pub fn is_desktop_widget<R: Runtime>(app_handle: &AppHandle<R>, label: &str) -> bool {
+    let state = app_handle.state::<WindowFlagsState>();
+    let flags = state.0.lock().unwrap();
+
+    flags.get(label).is_some_and(|flags| flags.desktop_widget)
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn record_desktop_widget<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    enabled: bool,
+) {
+    update_flags(app_handle, label, |flags| flags.desktop_widget = enabled);
+}
+
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+pub(crate) fn start_topmost<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    label: &str,
+) -> Option<Arc<AtomicBool>> {
+    let state = app_handle.state::<TopmostState>();
+    let mut windows = state.0.lock().unwrap();
+
+    if windows
+        .get(label)
+        .is_some_and(|running| running.load(Ordering::SeqCst))
+    {
+        return None;
+    }
+
+    let running = Arc::new(AtomicBool::new(true));
+
+    windows.insert(label.to_string(), Arc::clone(&running));
+
+    Some(running)
+}
+
+pub(crate) fn stop_topmost<R: Runtime>(app_handle: &AppHandle<R>, label: &str) {
+    let state = app_handle.state::<TopmostState>();
+
+    if let Some(running) = state.0.lock().unwrap().remove(label) {
+        running.store(false, Ordering::SeqCst);
+    }
+}
+
+#[command]
+pub async fn set_click_through<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    enabled: bool,
+) -> Result<()> {
+    window.set_ignore_cursor_events(enabled)?;
+
+    update_flags(&app_handle, window.label(), |flags| {
+        flags.click_through = enabled
+    });
+
+    Ok(())
+}
+
+#[command]
+pub async fn get_window_state<R: Runtime>(
+    app_handle: AppHandle<R>,
+    label: String,
+) -> Result<WindowState> {
+    let window = app_handle
+        .get_webview_window(&label)
+        .ok_or_else(|| Error::WindowNotFound(label.clone()))?;
+
+    let flags = {
+        let state = app_handle.state::<WindowFlagsState>();
+        let flags = state.0.lock().unwrap();
+
+        flags.get(&label).copied().unwrap_or_default()
+    };
+
+    Ok(WindowState {
+        visible: window.is_visible()?,
+        always_on_top: match flags.always_on_top {
+            Some(always_on_top) => always_on_top && !flags.lowered,
+            None => window.is_always_on_top()?,
+        },
+        always_on_bottom: flags.always_on_bottom || flags.lowered,
+        taskbar_visible: flags.taskbar_visible,
+        click_through: flags.click_through,
+        desktop_widget: flags.desktop_widget,
+        label,
+    })
+}
@@ -0,0 +1,63 @@
+use crate::Result;
+use tauri::{AppHandle, Runtime, WebviewWindow, command};
+
+#[cfg(target_os = "linux")]
+pub(crate) fn apply_desktop_widget<R: Runtime>(
+    window: &WebviewWindow<R>,
+    enabled: bool,
+) -> Result<()> {
+    use super::linux::apply_always_on_top;
+    use crate::{
+        Error,
+        x11::{self, X11State},
+    };
+    use tauri::Manager;
+
+    if enabled {
+        apply_always_on_top(window, false)?;
+    } else {
+        window.set_always_on_bottom(false)?;
+    }
+
+    window.set_visible_on_all_workspaces(enabled)?;
+
+    if let Some(xid) = x11::window_id(window) {
+        x11::set_desktop_widget(&window.state::<X11State>(), xid, enabled)
+            .map_err(|err| Error::Platform(err.to_string()))?;
+    }
+
+    Ok(())
+}
+
+#[command]
+pub async fn set_desktop_widget<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    enabled: bool,
+) -> Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        use super::state::{is_desktop_widget, record_desktop_widget, record_layer};
+
+        if is_desktop_widget(&app_handle, window.label()) == enabled {
+            return Ok(true);
+        }
+
+        apply_desktop_widget(&window, enabled)?;
+
+        record_desktop_widget(&app_handle, window.label(), enabled);
+
+        if enabled {
+            record_layer(&app_handle, window.label(), false, true);
+        }
+
+        Ok(true)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (app_handle, window, enabled);
+
+        Ok(false)
+    }
+}
@@ -1,5 +1,6 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
+use super::state::{record_layer, record_taskbar_visibility, start_topmost, stop_topmost};
+use crate::{Error, Result};
+use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
 use tauri::{AppHandle, Runtime, WebviewWindow, command};
@@ -8,77 +9,100 @@ use windows::Win32::UI::WindowsAndMessaging::{
     HWND_NOTOPMOST, HWND_TOPMOST, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SetWindowPos,
 };
 
-static TOPMOST_RUNNING: OnceLock<Arc<AtomicBool>> = OnceLock::new();
-
 #[command]
-pub async fn show_window<R: Runtime>(_app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    let _ = window.show();
-    let _ = window.unminimize();
-    let _ = window.set_focus();
+pub async fn show_window<R: Runtime>(
+    _app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<()> {
+    window.show()?;
+    window.unminimize()?;
+    window.set_focus()?;
+
+    Ok(())
 }
 
 #[command]
-pub async fn hide_window<R: Runtime>(_app_handle: AppHandle<R>, window: WebviewWindow<R>) {
-    let _ = window.hide();
+pub async fn hide_window<R: Runtime>(
+    _app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> Result<()> {
+    window.hide()?;
+
+    Ok(())
+}
+
+fn set_topmost(hwnd: HWND, topmost: bool) -> windows::core::Result<()> {
+    let insert_after = if topmost {
+        HWND_TOPMOST
+    } else {
+        HWND_NOTOPMOST
+    };
+
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            Some(insert_after),
+            0,
+            0,
+            0,
+            0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+        )
+    }
 }
 
 #[command]
 pub async fn set_always_on_top<R: Runtime>(
-    _app_handle: AppHandle<R>,
+    app_handle: AppHandle<R>,
     window: WebviewWindow<R>,
     always_on_top: bool,
-) {
-    let running = TOPMOST_RUNNING.get_or_init(|| Arc::new(AtomicBool::new(false)));
-
-    let Ok(hwnd) = window.hwnd() else { return };
-    let raw_hwnd = hwnd.0 as isize;
-
-    if always_on_top {
-        let Ok(_) = running.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
-        else {
-            return;
-        };
-
-        let running = Arc::clone(running);
-
-        thread::spawn(move || {
-            let hwnd = HWND(raw_hwnd as *mut _);
-
-            while running.load(Ordering::SeqCst) {
-                unsafe {
-                    let _ = SetWindowPos(
-                        hwnd,
-                        Some(HWND_TOPMOST),
-                        0,
-                        0,
-                        0,
-                        0,
-                        SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
-                    );
-                }
-                thread::sleep(Duration::from_millis(16));
-            }
-        });
-    } else {
-        running.store(false, Ordering::SeqCst);
+) -> Result<()> {
+    let raw_hwnd = window.hwnd()?.0 as isize;
 
+    if !always_on_top {
+        stop_topmost(&app_handle, window.label());
+    }
+
+    set_topmost(HWND(raw_hwnd as *mut _), always_on_top)
+        .map_err(|err| Error::Platform(err.to_string()))?;
+
+    record_layer(&app_handle, window.label(), always_on_top, false);
+
+    if !always_on_top {
+        return Ok(());
+    }
+
+    let Some(running) = start_topmost(&app_handle, window.label()) else {
+        return Ok(());
+    };
+
+    thread::spawn(move || {
         let hwnd = HWND(raw_hwnd as *mut _);
 
-        unsafe {
-            let _ = SetWindowPos(
-                hwnd,
-                Some(HWND_NOTOPMOST),
-                0,
-                0,
-                0,
-                0,
-                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
-            );
+        while running.load(Ordering::SeqCst) {
+            // The window is gone once the call fails, so there is nothing left to keep on top
+            if set_topmost(hwnd, true).is_err() {
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(16));
         }
-    }
+
+        running.store(false, Ordering::SeqCst);
+    });
+
+    Ok(())
 }
 
 #[command]
-pub async fn set_taskbar_visibility<R: Runtime>(window: WebviewWindow<R>, visible: bool) {
-    let _ = window.set_skip_taskbar(!visible);
+pub async fn set_taskbar_visibility<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    visible: bool,
+) -> Result<()> {
+    window.set_skip_taskbar(!visible)?;
+
+    record_taskbar_visibility(&app_handle, window.label(), visible);
+
+    Ok(())
 }
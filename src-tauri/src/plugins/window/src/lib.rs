@@ -1,11 +1,16 @@
 use tauri::{
-    Runtime, generate_handler,
+    Manager, RunEvent, Runtime, WindowEvent, generate_handler,
     plugin::{Builder, TauriPlugin},
 };
 
 mod commands;
+mod error;
+
+#[cfg(target_os = "linux")]
+mod x11;
 
 pub use commands::*;
+pub use error::{Error, Result};
 
 pub fn init<R: Runtime>() -> TauriPlugin<R> {
     Builder::new("custom-window")
@@ -14,6 +19,51 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             commands::hide_window,
             commands::set_always_on_top,
             commands::set_taskbar_visibility,
+            commands::snap_window,
+            commands::dock_window,
+            commands::set_follow_monitor,
+            commands::restore_window_position,
+            commands::set_input_shape,
+            commands::set_fullscreen_watch,
+            commands::set_click_through,
+            commands::get_window_state,
+            commands::set_overlay_mode,
+            commands::set_desktop_widget,
+            commands::set_focus_watch,
         ])
+        .setup(|app_handle, _api| {
+            app_handle.manage(DockState::default());
+            app_handle.manage(FocusState::default());
+            app_handle.manage(FollowState::default());
+            app_handle.manage(FullscreenState::default());
+            app_handle.manage(TopmostState::default());
+            app_handle.manage(WindowFlagsState::default());
+
+            #[cfg(target_os = "linux")]
+            app_handle.manage(x11::X11State::default());
+
+            init_layout(app_handle);
+
+            Ok(())
+        })
+        .on_event(|app_handle, event| {
+            if let RunEvent::WindowEvent { label, event, .. } = event {
+                match event {
+                    WindowEvent::Moved(position) => {
+                        remember_position(app_handle, label, *position);
+                    }
+                    WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } => {
+                        redock(app_handle, label);
+                    }
+                    WindowEvent::Destroyed => {
+                        stop_topmost(app_handle, label);
+
+                        #[cfg(target_os = "linux")]
+                        app_handle.state::<x11::X11State>().forget_window(label);
+                    }
+                    _ => {}
+                }
+            }
+        })
         .build()
 }
@@ -0,0 +1,434 @@
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use std::{
+    collections::HashMap,
+    error::Error,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+use tauri::{PhysicalPosition, PhysicalSize, Runtime, WebviewWindow};
+use x11rb::{
+    NONE, atom_manager,
+    connection::Connection,
+    protocol::{
+        Event,
+        shape::{ConnectionExt as _, SK, SO},
+        xproto::{
+            Atom, AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, ClipOrdering,
+            ConnectionExt, EventMask, MapState, PropMode, Rectangle, UNMAP_NOTIFY_EVENT,
+            UnmapNotifyEvent, Window,
+        },
+    },
+    rust_connection::RustConnection,
+    wrapper::ConnectionExt as _,
+};
+
+use crate::commands::MaskRect;
+
+pub type X11Result<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+const NET_WM_STATE_REMOVE: u32 = 0;
+const NET_WM_STATE_ADD: u32 = 1;
+const SOURCE_INDICATION_APPLICATION: u32 = 1;
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+atom_manager! {
+    pub Atoms: AtomsCookie {
+        _NET_ACTIVE_WINDOW,
+        _NET_WM_STATE,
+        _NET_WM_STATE_ABOVE,
+        _NET_WM_STATE_FULLSCREEN,
+        _NET_WM_STATE_STICKY,
+        _NET_WM_STATE_BELOW,
+        _NET_WM_STATE_SKIP_PAGER,
+        _NET_WM_STATE_SKIP_TASKBAR,
+        _NET_WM_WINDOW_TYPE,
+        _NET_WM_WINDOW_TYPE_DESKTOP,
+        _NET_WM_WINDOW_TYPE_UTILITY,
+        _NET_WM_WINDOW_TYPE_NORMAL,
+    }
+}
+
+pub struct X11Connection {
+    pub conn: RustConnection,
+    pub root: Window,
+    pub atoms: Atoms,
+}
+
+// One connection shared by the one-off requests, instead of connecting for every call
+#[derive(Default)]
+pub struct X11State {
+    connection: Mutex<Option<X11Connection>>,
+    shapes: Mutex<HashMap<String, Option<Vec<MaskRect>>>>,
+}
+
+impl X11State {
+    fn with_connection<T>(&self, f: impl FnOnce(&X11Connection) -> X11Result<T>) -> X11Result<T> {
+        let mut connection = self.connection.lock().unwrap();
+
+        let x11 = match connection.take() {
+            Some(x11) => x11,
+            None => X11Connection::connect()?,
+        };
+
+        let result = f(&x11);
+
+        // A failed request drops the connection, so the next call reconnects in case it was lost
+        if result.is_ok() {
+            *connection = Some(x11);
+        }
+
+        result
+    }
+
+    pub fn forget_window(&self, label: &str) {
+        self.shapes.lock().unwrap().remove(label);
+    }
+}
+
+impl X11Connection {
+    pub fn connect() -> X11Result<Self> {
+        let (conn, screen_num) = x11rb::connect(None)?;
+
+        let root = conn.setup().roots[screen_num].root;
+
+        let atoms = Atoms::new(&conn)?.reply()?;
+
+        Ok(Self { conn, root, atoms })
+    }
+
+    pub fn select_input(&self, window: Window, event_mask: EventMask) -> X11Result<()> {
+        let values = ChangeWindowAttributesAux::new().event_mask(event_mask);
+
+        self.conn
+            .change_window_attributes(window, &values)?
+            .check()?;
+
+        Ok(())
+    }
+
+    pub fn states(&self, window: Window) -> X11Result<Vec<Atom>> {
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                window,
+                self.atoms._NET_WM_STATE,
+                AtomEnum::ATOM,
+                0,
+                u32::MAX,
+            )?
+            .reply()?;
+
+        Ok(reply
+            .value32()
+            .map(|states| states.collect())
+            .unwrap_or_default())
+    }
+
+    pub fn set_states(&self, window: Window, enabled: bool, states: [Atom; 2]) -> X11Result<()> {
+        let action = if enabled {
+            NET_WM_STATE_ADD
+        } else {
+            NET_WM_STATE_REMOVE
+        };
+
+        let event = ClientMessageEvent::new(
+            32,
+            window,
+            self.atoms._NET_WM_STATE,
+            [
+                action,
+                states[0],
+                states[1],
+                SOURCE_INDICATION_APPLICATION,
+                0,
+            ],
+        );
+
+        self.conn.send_event(
+            false,
+            self.root,
+            EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+
+        self.conn.flush()?;
+
+        Ok(())
+    }
+
+    pub fn active_window(&self) -> X11Result<Option<Window>> {
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                self.root,
+                self.atoms._NET_ACTIVE_WINDOW,
+                AtomEnum::WINDOW,
+                0,
+                1,
+            )?
+            .reply()?;
+
+        Ok(reply
+            .value32()
+            .and_then(|mut windows| windows.next())
+            .filter(|window| *window != 0))
+    }
+
+    pub fn window_rect(
+        &self,
+        window: Window,
+    ) -> X11Result<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        let geometry = self.conn.get_geometry(window)?.reply()?;
+
+        let translated = self
+            .conn
+            .translate_coordinates(window, self.root, 0, 0)?
+            .reply()?;
+
+        Ok((
+            PhysicalPosition::new(translated.dst_x as i32, translated.dst_y as i32),
+            PhysicalSize::new(geometry.width as u32, geometry.height as u32),
+        ))
+    }
+
+    pub fn is_fullscreen(&self, window: Window) -> X11Result<bool> {
+        let states = self.states(window)?;
+
+        Ok(states.contains(&self.atoms._NET_WM_STATE_FULLSCREEN))
+    }
+
+    pub fn wm_class(&self, window: Window) -> X11Result<Vec<String>> {
+        let reply = self
+            .conn
+            .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 1024)?
+            .reply()?;
+
+        Ok(reply
+            .value
+            .split(|byte| *byte == 0)
+            .filter(|part| !part.is_empty())
+            .map(|part| String::from_utf8_lossy(part).to_string())
+            .collect())
+    }
+
+    fn window_types(&self, window: Window) -> X11Result<Vec<Atom>> {
+        let reply = self
+            .conn
+            .get_property(
+                false,
+                window,
+                self.atoms._NET_WM_WINDOW_TYPE,
+                AtomEnum::ATOM,
+                0,
+                u32::MAX,
+            )?
+            .reply()?;
+
+        Ok(reply
+            .value32()
+            .map(|types| types.collect())
+            .unwrap_or_default())
+    }
+
+    fn is_mapped(&self, window: Window) -> X11Result<bool> {
+        let attributes = self.conn.get_window_attributes(window)?.reply()?;
+
+        Ok(attributes.map_state != MapState::UNMAPPED)
+    }
+
+    // Unmaps the window and tells the window manager it is withdrawn, as ICCCM 4.1.4 asks
+    fn withdraw(&self, window: Window) -> X11Result<()> {
+        self.conn.unmap_window(window)?.check()?;
+
+        let event = UnmapNotifyEvent {
+            response_type: UNMAP_NOTIFY_EVENT,
+            sequence: 0,
+            event: self.root,
+            window,
+            from_configure: false,
+        };
+
+        self.conn.send_event(
+            false,
+            self.root,
+            EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+
+        self.conn.flush()?;
+
+        Ok(())
+    }
+
+    fn widget_states(&self) -> [[Atom; 2]; 2] {
+        [
+            [
+                self.atoms._NET_WM_STATE_SKIP_PAGER,
+                self.atoms._NET_WM_STATE_SKIP_TASKBAR,
+            ],
+            [
+                self.atoms._NET_WM_STATE_STICKY,
+                self.atoms._NET_WM_STATE_BELOW,
+            ],
+        ]
+    }
+
+    fn above_states(&self) -> [Atom; 2] {
+        [
+            self.atoms._NET_WM_STATE_ABOVE,
+            self.atoms._NET_WM_STATE_STICKY,
+        ]
+    }
+}
+
+pub fn window_id<R: Runtime>(window: &WebviewWindow<R>) -> Option<Window> {
+    let handle = window.window_handle().ok()?;
+
+    match handle.as_raw() {
+        RawWindowHandle::Xlib(handle) => Some(handle.window as Window),
+        RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+        _ => None,
+    }
+}
+
+pub fn set_above(state: &X11State, window: Window, enabled: bool) -> X11Result<()> {
+    state.with_connection(|x11| x11.set_states(window, enabled, x11.above_states()))
+}
+
+pub fn keep_above(window: Window, running: &AtomicBool) -> X11Result<()> {
+    let x11 = X11Connection::connect()?;
+    let above_states = x11.above_states();
+
+    x11.select_input(
+        window,
+        EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY,
+    )?;
+
+    x11.set_states(window, true, above_states)?;
+
+    while running.load(Ordering::SeqCst) {
+        while let Some(event) = x11.conn.poll_for_event()? {
+            let should_reassert = match event {
+                Event::PropertyNotify(event) if event.atom == x11.atoms._NET_WM_STATE => {
+                    let states = x11.states(window)?;
+
+                    !above_states.iter().all(|state| states.contains(state))
+                }
+                Event::MapNotify(_) => true,
+                _ => false,
+            };
+
+            if should_reassert {
+                x11.set_states(window, true, above_states)?;
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    Ok(())
+}
+
+pub fn set_desktop_widget(state: &X11State, window: Window, enabled: bool) -> X11Result<()> {
+    state.with_connection(|x11| {
+        // Window managers list types in order of preference, so fall back to a utility window.
+        let types = if enabled {
+            vec![
+                x11.atoms._NET_WM_WINDOW_TYPE_DESKTOP,
+                x11.atoms._NET_WM_WINDOW_TYPE_UTILITY,
+            ]
+        } else {
+            vec![x11.atoms._NET_WM_WINDOW_TYPE_NORMAL]
+        };
+
+        if x11.window_types(window)? != types {
+            // Most window managers only read the type when a window is mapped, so map it again
+            let mapped = x11.is_mapped(window)?;
+
+            if mapped {
+                x11.withdraw(window)?;
+            }
+
+            x11.conn
+                .change_property32(
+                    PropMode::REPLACE,
+                    window,
+                    x11.atoms._NET_WM_WINDOW_TYPE,
+                    AtomEnum::ATOM,
+                    &types,
+                )?
+                .check()?;
+
+            if mapped {
+                x11.conn.map_window(window)?.check()?;
+            }
+        }
+
+        for states in x11.widget_states() {
+            x11.set_states(window, enabled, states)?;
+        }
+
+        Ok(())
+    })
+}
+
+pub fn set_input_shape(
+    state: &X11State,
+    label: &str,
+    window: Window,
+    rects: Option<Vec<MaskRect>>,
+) -> X11Result<()> {
+    let mut shapes = state.shapes.lock().unwrap();
+
+    // The mask is sampled on a timer, so most calls would resend the same shape
+    if shapes.get(label) == Some(&rects) {
+        return Ok(());
+    }
+
+    shapes.remove(label);
+
+    state.with_connection(|x11| {
+        match &rects {
+            Some(rects) => {
+                let rectangles: Vec<Rectangle> = rects
+                    .iter()
+                    .map(|rect| Rectangle {
+                        x: rect.x as i16,
+                        y: rect.y as i16,
+                        width: rect.width as u16,
+                        height: rect.height as u16,
+                    })
+                    .collect();
+
+                x11.conn
+                    .shape_rectangles(
+                        SO::SET,
+                        SK::INPUT,
+                        ClipOrdering::UNSORTED,
+                        window,
+                        0,
+                        0,
+                        &rectangles,
+                    )?
+                    .check()?;
+            }
+            None => {
+                x11.conn
+                    .shape_mask(SO::SET, SK::INPUT, window, 0, 0, NONE)?
+                    .check()?;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    shapes.insert(label.to_string(), rects);
+
+    Ok(())
+}
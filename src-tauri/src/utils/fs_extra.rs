@@ -1,15 +1,156 @@
-use fs_extra::dir::{CopyOptions, copy};
-use std::fs::create_dir_all;
-use tauri::command;
+use fs_extra::dir::{DirOptions, get_dir_content2};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fs::{File, create_dir_all, remove_dir_all},
+    io::{Read, Write},
+    path::Path,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Emitter, Runtime, async_runtime::spawn_blocking, command};
 
-#[command]
-pub async fn copy_dir(from_path: String, to_path: String) -> Result<(), String> {
-    let mut options = CopyOptions::new();
-    options.content_only = true;
+const BUFFER_SIZE: usize = 64 * 1024;
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
 
-    create_dir_all(&to_path).map_err(|err| err.to_string())?;
+// In-flight copies by id, flagged once they are cancelled
+static ACTIVE_COPIES: LazyLock<Mutex<HashMap<String, bool>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyDirProgress {
+    id: String,
+    copied_bytes: u64,
+    total_bytes: u64,
+    copied_files: usize,
+    total_files: usize,
+    file_name: String,
+}
+
+fn is_cancelled(id: &str) -> bool {
+    ACTIVE_COPIES
+        .lock()
+        .unwrap()
+        .get(id)
+        .is_some_and(|cancelled| *cancelled)
+}
+
+fn copy_dir_with_progress(
+    id: &str,
+    from_path: &Path,
+    to_path: &Path,
+    mut on_progress: impl FnMut(&CopyDirProgress),
+) -> Result<(), String> {
+    let content = get_dir_content2(from_path, &DirOptions::new()).map_err(|err| err.to_string())?;
+
+    let mut progress = CopyDirProgress {
+        id: id.to_string(),
+        copied_bytes: 0,
+        total_bytes: content.dir_size,
+        copied_files: 0,
+        total_files: content.files.len(),
+        file_name: String::new(),
+    };
+
+    for directory in &content.directories {
+        let relative_path = Path::new(directory)
+            .strip_prefix(from_path)
+            .map_err(|err| err.to_string())?;
+
+        create_dir_all(to_path.join(relative_path)).map_err(|err| err.to_string())?;
+    }
+
+    on_progress(&progress);
+
+    let mut last_emitted = Instant::now();
+    let mut buffer = vec![0; BUFFER_SIZE];
+
+    for file in &content.files {
+        let source = Path::new(file);
+        let relative_path = source
+            .strip_prefix(from_path)
+            .map_err(|err| err.to_string())?;
+
+        progress.file_name = relative_path.to_string_lossy().to_string();
+
+        let mut reader = File::open(source).map_err(|err| err.to_string())?;
+        let mut writer =
+            File::create(to_path.join(relative_path)).map_err(|err| err.to_string())?;
+
+        loop {
+            if is_cancelled(id) {
+                return Err(format!("Copy cancelled: {}", id));
+            }
+
+            let read = reader.read(&mut buffer).map_err(|err| err.to_string())?;
+
+            if read == 0 {
+                break;
+            }
 
-    copy(from_path, to_path, &options).map_err(|err| err.to_string())?;
+            writer
+                .write_all(&buffer[..read])
+                .map_err(|err| err.to_string())?;
+
+            progress.copied_bytes += read as u64;
+
+            if last_emitted.elapsed() >= PROGRESS_INTERVAL {
+                on_progress(&progress);
+
+                last_emitted = Instant::now();
+            }
+        }
+
+        progress.copied_files += 1;
+    }
+
+    on_progress(&progress);
 
     Ok(())
 }
+
+#[command]
+pub async fn copy_dir<R: Runtime>(
+    app_handle: AppHandle<R>,
+    id: String,
+    from_path: String,
+    to_path: String,
+) -> Result<(), String> {
+    create_dir_all(&to_path).map_err(|err| err.to_string())?;
+
+    ACTIVE_COPIES.lock().unwrap().insert(id.clone(), false);
+
+    let task_id = id.clone();
+    let task_to_path = to_path.clone();
+
+    let result = spawn_blocking(move || {
+        copy_dir_with_progress(
+            &task_id,
+            Path::new(&from_path),
+            Path::new(&task_to_path),
+            |progress| {
+                let _ = app_handle.emit("copy-dir-progress", progress);
+            },
+        )
+    })
+    .await
+    .map_err(|err| err.to_string())
+    .and_then(|result| result);
+
+    ACTIVE_COPIES.lock().unwrap().remove(&id);
+
+    if result.is_err() {
+        let _ = remove_dir_all(&to_path);
+    }
+
+    result
+}
+
+#[command]
+pub async fn cancel_copy_dir(id: String) {
+    // Ids that are not copying are ignored, so they can't cancel a later copy
+    if let Some(cancelled) = ACTIVE_COPIES.lock().unwrap().get_mut(&id) {
+        *cancelled = true;
+    }
+}
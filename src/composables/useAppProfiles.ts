@@ -0,0 +1,37 @@
+import { watch } from 'vue'
+
+import { LISTEN_KEY } from '@/constants'
+import { setFocusWatch } from '@/plugins/window'
+import { useCatStore } from '@/stores/cat'
+import { isLinux } from '@/utils/platform'
+
+import { useTauriListen } from './useTauriListen'
+
+interface FocusChangedEvent {
+  classes: string[]
+}
+
+export function useAppProfiles() {
+  const catStore = useCatStore()
+
+  watch(() => catStore.profile.enabled, (enabled) => {
+    if (!isLinux) return
+
+    setFocusWatch(enabled)
+
+    if (enabled) return
+
+    catStore.activeProfile = void 0
+  }, { immediate: true })
+
+  useTauriListen<FocusChangedEvent>(LISTEN_KEY.FOCUS_CHANGED, ({ payload }) => {
+    if (!catStore.profile.enabled) return
+
+    const classes = payload.classes.map(item => item.toLowerCase())
+
+    // Profiles only override what is shown, so the saved settings come back once none matches
+    catStore.activeProfile = catStore.profile.items.find(({ wmClass }) => {
+      return wmClass && classes.includes(wmClass.trim().toLowerCase())
+    })
+  })
+}
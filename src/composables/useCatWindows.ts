@@ -0,0 +1,147 @@
+import type { InputSource } from '@/stores/cat'
+
+import { invoke } from '@tauri-apps/api/core'
+import { CheckMenuItem, MenuItem, Submenu } from '@tauri-apps/api/menu'
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
+import { find } from 'es-toolkit/compat'
+import { nanoid } from 'nanoid'
+import { computed, onMounted, watch } from 'vue'
+import { useI18n } from 'vue-i18n'
+
+import { CAT_WINDOW_PREFIX, INVOKE_KEY, WINDOW_LABEL } from '@/constants'
+import { useAppStore } from '@/stores/app'
+import { useCatStore } from '@/stores/cat'
+import { useModelStore } from '@/stores/model'
+
+const SPAWN_OFFSET = 40
+const INPUT_SOURCES: InputSource[] = ['all', 'keyboard', 'mouse', 'gamepad']
+
+const appWindow = getCurrentWebviewWindow()
+const { label } = appWindow
+
+export function useCatWindows() {
+  const appStore = useAppStore()
+  const catStore = useCatStore()
+  const modelStore = useModelStore()
+  const { t } = useI18n()
+  const isMainCat = label === WINDOW_LABEL.MAIN
+
+  const catModel = computed(() => {
+    if (isMainCat) {
+      return find(modelStore.models, { id: catStore.activeProfile?.modelId }) ?? modelStore.currentModel
+    }
+
+    const modelId = catStore.instances[label]?.modelId
+
+    return find(modelStore.models, { id: modelId }) ?? modelStore.currentModel
+  })
+
+  onMounted(() => {
+    if (!isMainCat) return
+
+    for (const catLabel of Object.keys(catStore.instances)) {
+      if (!catLabel.startsWith(CAT_WINDOW_PREFIX)) continue
+
+      invoke(INVOKE_KEY.CREATE_CAT_WINDOW, { label: catLabel })
+    }
+  })
+
+  watch(() => catStore.instances, (instances) => {
+    if (!isMainCat) return
+
+    for (const [catLabel, { inputSource }] of Object.entries(instances)) {
+      invoke(INVOKE_KEY.SET_INPUT_SOURCE, { label: catLabel, source: inputSource })
+    }
+  }, { deep: true, immediate: true })
+
+  const addCat = async () => {
+    const catLabel = `${CAT_WINDOW_PREFIX}${nanoid(8)}`
+    const position = await appWindow.outerPosition()
+
+    catStore.instances[catLabel] = {
+      modelId: catModel.value?.id,
+      inputSource: 'all',
+    }
+
+    return invoke(INVOKE_KEY.CREATE_CAT_WINDOW, {
+      label: catLabel,
+      position: {
+        x: position.x + SPAWN_OFFSET,
+        y: position.y + SPAWN_OFFSET,
+      },
+    })
+  }
+
+  const removeCat = () => {
+    delete catStore.instances[label]
+    delete appStore.windowState[label]
+
+    return invoke(INVOKE_KEY.CLOSE_CAT_WINDOW, { label })
+  }
+
+  const setInstance = (key: 'modelId' | 'inputSource', value: string) => {
+    catStore.instances[label] ??= { inputSource: 'all' }
+
+    Object.assign(catStore.instances[label], { [key]: value })
+  }
+
+  const getModelMenuItems = () => {
+    const items = modelStore.models.map((model, index) => {
+      return CheckMenuItem.new({
+        text: `${index + 1}. ${t(`composables.useCatWindows.modes.${model.mode}`)}`,
+        checked: model.id === catModel.value?.id,
+        action: () => setInstance('modelId', model.id),
+      })
+    })
+
+    return Promise.all(items)
+  }
+
+  const getInputSourceMenuItems = () => {
+    const inputSource = catStore.instances[label]?.inputSource ?? 'all'
+
+    const items = INPUT_SOURCES.map((source) => {
+      return CheckMenuItem.new({
+        text: t(`composables.useCatWindows.inputSources.${source}`),
+        checked: source === inputSource,
+        action: () => setInstance('inputSource', source),
+      })
+    })
+
+    return Promise.all(items)
+  }
+
+  const getCatMenu = async () => {
+    const items = [
+      MenuItem.new({
+        text: t('composables.useCatWindows.labels.addCat'),
+        action: addCat,
+      }),
+      Submenu.new({
+        text: t('composables.useCatWindows.labels.inputSource'),
+        items: await getInputSourceMenuItems(),
+      }),
+    ]
+
+    if (!isMainCat) {
+      items.push(
+        Submenu.new({
+          text: t('composables.useCatWindows.labels.model'),
+          items: await getModelMenuItems(),
+        }),
+        MenuItem.new({
+          text: t('composables.useCatWindows.labels.removeCat'),
+          action: removeCat,
+        }),
+      )
+    }
+
+    return Promise.all(items)
+  }
+
+  return {
+    isMainCat,
+    catModel,
+    getCatMenu,
+  }
+}
@@ -0,0 +1,66 @@
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
+import { find } from 'es-toolkit/compat'
+
+import { LISTEN_KEY, WINDOW_LABEL } from '@/constants'
+import { useCatStore } from '@/stores/cat'
+import { useModelStore } from '@/stores/model'
+
+import { useTauriListen } from './useTauriListen'
+
+interface VisibilityCommand {
+  kind: 'show' | 'hide' | 'toggle'
+}
+
+interface ModelCommand {
+  kind: 'model'
+  value: string
+}
+
+interface ToggleCommand {
+  kind: 'mirror' | 'passThrough' | 'alwaysOnTop'
+  value: boolean
+}
+
+interface PercentCommand {
+  kind: 'scale' | 'opacity'
+  value: number
+}
+
+export type ControlCommand = VisibilityCommand | ModelCommand | ToggleCommand | PercentCommand
+
+const appWindow = getCurrentWebviewWindow()
+
+export function useControl() {
+  const catStore = useCatStore()
+  const modelStore = useModelStore()
+
+  useTauriListen<ControlCommand>(LISTEN_KEY.CONTROL_COMMAND, ({ payload }) => {
+    if (appWindow.label !== WINDOW_LABEL.MAIN) return
+
+    switch (payload.kind) {
+      case 'show':
+        return catStore.window.visible = true
+      case 'hide':
+        return catStore.window.visible = false
+      case 'toggle':
+        return catStore.window.visible = !catStore.window.visible
+      case 'model': {
+        const model = find(modelStore.models, { id: payload.value })
+
+        if (!model) return
+
+        return modelStore.currentModel = model
+      }
+      case 'mirror':
+        return catStore.model.mirror = payload.value
+      case 'passThrough':
+        return catStore.window.passThrough = payload.value
+      case 'alwaysOnTop':
+        return catStore.window.alwaysOnTop = payload.value
+      case 'scale':
+        return catStore.window.scale = payload.value
+      case 'opacity':
+        return catStore.window.opacity = payload.value
+    }
+  })
+}
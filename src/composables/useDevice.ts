@@ -5,13 +5,14 @@ import { isNil } from 'es-toolkit'
 import { Ticker } from 'pixi.js'
 import { onMounted, onUnmounted, ref, watch } from 'vue'
 
+import { setClickThrough } from '@/plugins/window'
 import { useAppStore } from '@/stores/app'
 import { useCatStore } from '@/stores/cat'
 import { useModelStore } from '@/stores/model'
 import { inBetween } from '@/utils/is'
 import { isMac, isWindows } from '@/utils/platform'
 
-import { INVOKE_KEY, LISTEN_KEY, WINDOW_LABEL } from '../constants'
+import { INVOKE_KEY, LISTEN_KEY } from '../constants'
 import { useModel } from './useModel'
 import { useTauriListen } from './useTauriListen'
 
@@ -125,7 +126,7 @@ export function useDevice() {
     let wasInWindow = false
 
     return (x: number, y: number) => {
-      const { x: winX, y: winY, width, height } = appStore.windowState[WINDOW_LABEL.MAIN] ?? {}
+      const { x: winX, y: winY, width, height } = appStore.windowState[appWindow.label] ?? {}
 
       if (isNil(winX) || isNil(winY) || isNil(width) || isNil(height)) return
 
@@ -144,12 +145,12 @@ export function useDevice() {
         timer = setTimeout(() => {
           document.body.style.setProperty('opacity', '0')
 
-          appWindow.setIgnoreCursorEvents(true)
+          setClickThrough(true)
         }, catStore.window.hideOnHoverDelay * 1000)
       } else {
         document.body.style.setProperty('opacity', 'unset')
 
-        appWindow.setIgnoreCursorEvents(catStore.window.passThrough)
+        setClickThrough(catStore.window.passThrough)
       }
 
       wasInWindow = isInWindow
@@ -216,7 +217,7 @@ export function useDevice() {
       case 'MouseMove':
         return latestCursorPoint.value = value
     }
-  })
+  }, { target: appWindow.label })
 
   return {
     startListening,
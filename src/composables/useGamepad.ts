@@ -1,9 +1,12 @@
 import type { LiteralUnion } from 'type-fest'
 
 import { invoke } from '@tauri-apps/api/core'
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
+import { find } from 'es-toolkit/compat'
 import { computed, reactive, watch } from 'vue'
 
-import { INVOKE_KEY, LISTEN_KEY } from '@/constants'
+import { CAT_WINDOW_PREFIX, INVOKE_KEY, LISTEN_KEY, WINDOW_LABEL } from '@/constants'
+import { useCatStore } from '@/stores/cat'
 import { useModelStore } from '@/stores/model'
 import live2d from '@/utils/live2d'
 
@@ -31,9 +34,11 @@ interface Sticks {
 }
 
 const INITIAL_STICK_STATE: StickState = { x: 0, y: 0, moved: false, pressed: false }
+const appWindow = getCurrentWebviewWindow()
 
 export function useGamepad() {
   const modelStore = useModelStore()
+  const catStore = useCatStore()
   const { handlePress, handleRelease, handleAxisChange } = useModel()
   const sticks = reactive<Sticks>({
     left: { ...INITIAL_STICK_STATE },
@@ -45,8 +50,21 @@ export function useGamepad() {
     right: sticks.right.moved || sticks.right.pressed,
   }))
 
-  watch(() => modelStore.currentModel?.mode, (mode) => {
-    if (mode === 'gamepad') {
+  const gamepadRequired = computed(() => {
+    const catModels = Object.entries(catStore.instances)
+      .filter(([label]) => label.startsWith(CAT_WINDOW_PREFIX))
+      .map(([, { modelId }]) => find(modelStore.models, { id: modelId }))
+
+    const profileModel = find(modelStore.models, { id: catStore.activeProfile?.modelId })
+
+    return [modelStore.currentModel, profileModel, ...catModels].some(model => model?.mode === 'gamepad')
+  })
+
+  watch(gamepadRequired, (required) => {
+    // Gamepad listening is shared by every cat window, so only the main cat manages it
+    if (appWindow.label !== WINDOW_LABEL.MAIN) return
+
+    if (required) {
       return invoke(INVOKE_KEY.START_GAMEPAD_LISTING)
     }
 
@@ -96,7 +114,7 @@ export function useGamepad() {
       default:
         return value > 0 ? handlePress(name) : handleRelease(name)
     }
-  })
+  }, { target: appWindow.label })
 
   return {
     stickActive,
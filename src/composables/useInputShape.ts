@@ -0,0 +1,73 @@
+import { useIntervalFn } from '@vueuse/core'
+import { ref, watch } from 'vue'
+
+import { setInputShape } from '@/plugins/window'
+import { useCatStore } from '@/stores/cat'
+import { useModelStore } from '@/stores/model'
+import live2d from '@/utils/live2d'
+import { isLinux } from '@/utils/platform'
+
+const MASK_WIDTH = 96
+const UPDATE_INTERVAL = 500
+
+export function useInputShape() {
+  const catStore = useCatStore()
+  const modelStore = useModelStore()
+  const backgroundRef = ref<HTMLImageElement>()
+  const canvas = document.createElement('canvas')
+  const context = canvas.getContext('2d', { willReadFrequently: true })
+
+  const updateInputShape = () => {
+    if (!context || !modelStore.modelReady) return
+
+    const width = Math.min(innerWidth, MASK_WIDTH)
+    const height = Math.max(1, Math.round(innerHeight * (width / innerWidth)))
+
+    canvas.width = width
+    canvas.height = height
+
+    context.save()
+
+    if (catStore.mirrored) {
+      context.translate(width, 0)
+      context.scale(-1, 1)
+    }
+
+    if (backgroundRef.value?.complete) {
+      context.drawImage(backgroundRef.value, 0, 0, width, height)
+    }
+
+    const modelCanvas = live2d.extractCanvas()
+
+    if (modelCanvas) {
+      context.drawImage(modelCanvas, 0, 0, width, height)
+    }
+
+    context.restore()
+
+    const { data } = context.getImageData(0, 0, width, height)
+
+    const alpha = data.filter((_, index) => index % 4 === 3)
+
+    return setInputShape({ width, height, alpha: Array.from(alpha) })
+  }
+
+  const { pause, resume } = useIntervalFn(updateInputShape, UPDATE_INTERVAL, { immediate: false })
+
+  watch([() => catStore.window.transparentPassThrough, () => catStore.window.passThrough], ([transparentPassThrough, passThrough]) => {
+    // Input shapes are only applied on Linux, so skip the readback everywhere else
+    if (!isLinux) return
+
+    if (transparentPassThrough && !passThrough) {
+      return resume()
+    }
+
+    pause()
+
+    setInputShape(null)
+  }, { immediate: true })
+
+  return {
+    backgroundRef,
+  }
+}
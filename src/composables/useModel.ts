@@ -8,6 +8,9 @@ import { isNil, round } from 'es-toolkit'
 import { findKey, nth } from 'es-toolkit/compat'
 import { ref } from 'vue'
 
+import type { Model } from '@/stores/model'
+
+import { WINDOW_LABEL } from '@/constants'
 import { useCatStore } from '@/stores/cat'
 import { useModelStore } from '@/stores/model'
 import { getCursorMonitor } from '@/utils/monitor'
@@ -65,11 +68,9 @@ export function useModel() {
     return `${modelId}:expression:${index}`
   }
 
-  async function handleLoad() {
+  async function handleLoad(model: Model) {
     try {
-      if (!modelStore.currentModel) return
-
-      const { path } = modelStore.currentModel
+      const { path } = model
 
       await resolveResource(path)
 
@@ -78,12 +79,16 @@ export function useModel() {
       const nextMotions = Object.entries(motions)
 
       modelSize.value = { width, height }
-      modelStore.currentMotions = nextMotions
-      modelStore.currentExpressions = expressions
 
       handleResize()
 
-      const modelId = modelStore.currentModel.id
+      // Behaviors and shortcuts belong to the model picked in preferences, which the main cat shows
+      if (appWindow.label !== WINDOW_LABEL.MAIN) return
+
+      modelStore.currentMotions = nextMotions
+      modelStore.currentExpressions = expressions
+
+      const modelId = model.id
 
       const behaviorIds: string[] = []
 
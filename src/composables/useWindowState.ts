@@ -7,10 +7,9 @@ import { useDebounceFn } from '@vueuse/core'
 import { isNumber } from 'es-toolkit/compat'
 import { onMounted, ref, watch } from 'vue'
 
-import { WINDOW_LABEL } from '@/constants'
+import { isCatWindow, restoreWindowPosition, snapWindow } from '@/plugins/window'
 import { useAppStore } from '@/stores/app'
 import { useCatStore } from '@/stores/cat'
-import { getCursorMonitor } from '@/utils/monitor'
 
 export type WindowState = Record<string, Partial<PhysicalPosition & PhysicalSize> | undefined>
 
@@ -30,31 +29,16 @@ export function useWindowState() {
     appWindow.onScaleChanged(clampToMonitor)
   })
 
-  const clampToMonitor = useDebounceFn(async () => {
-    if (label !== WINDOW_LABEL.MAIN || !catStore.window.keepInScreen) return
+  const clampToMonitor = useDebounceFn(() => {
+    if (!isCatWindow(label)) return
 
-    const monitor = await getCursorMonitor()
+    const { keepInScreen, snapDistance } = catStore.window
 
-    if (!monitor) return
-
-    const { position: monitorPos, size: monitorSize } = monitor
-    const windowSize = await appWindow.outerSize()
-    const windowPos = await appWindow.outerPosition()
-
-    const minX = monitorPos.x
-    const maxX = monitorPos.x + monitorSize.width - windowSize.width
-    const minY = monitorPos.y
-    const maxY = monitorPos.y + monitorSize.height - windowSize.height
-
-    const clampedX = Math.max(minX, Math.min(windowPos.x, maxX))
-    const clampedY = Math.max(minY, Math.min(windowPos.y, maxY))
-
-    if (clampedX === windowPos.x && clampedY === windowPos.y) return
-
-    return appWindow.setPosition(new PhysicalPosition(clampedX, clampedY))
+    // Snapping is opt-in through the snap distance, keepInScreen only decides whether to clamp
+    return snapWindow(snapDistance, keepInScreen)
   }, 500)
 
-  watch(() => catStore.window.keepInScreen, clampToMonitor)
+  watch([() => catStore.window.keepInScreen, () => catStore.window.snapDistance], clampToMonitor)
 
   const onChange = async (event: Event<PhysicalPosition | PhysicalSize>) => {
     const minimized = await appWindow.isMinimized()
@@ -71,7 +55,9 @@ export function useWindowState() {
   const restoreState = async () => {
     const { x, y, width, height } = appStore.windowState[label] ?? {}
 
-    if (isNumber(x) && isNumber(y)) {
+    const layoutRestored = await restoreWindowPosition()
+
+    if (!layoutRestored && isNumber(x) && isNumber(y)) {
       const monitors = await availableMonitors()
 
       const monitor = monitors.find((monitor) => {
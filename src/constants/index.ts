@@ -10,13 +10,44 @@ export const LISTEN_KEY = {
   GAMEPAD_CHANGED: 'gamepad-changed',
   START_MOTION: 'start-motion',
   SET_EXPRESSION: 'set-expression',
+  COPY_DIR_PROGRESS: 'copy-dir-progress',
+  MODEL_RELOAD: 'model-reload',
+  CONTROL_COMMAND: 'control-command',
+  SET_PARAMETER: 'set-parameter',
+  SHOW_MESSAGE: 'show-message',
+  FOCUS_CHANGED: 'focus-changed',
 }
 
 export const INVOKE_KEY = {
   COPY_DIR: 'copy_dir',
+  CANCEL_COPY_DIR: 'cancel_copy_dir',
   START_DEVICE_LISTENING: 'start_device_listening',
   START_GAMEPAD_LISTING: 'start_gamepad_listing',
   STOP_GAMEPAD_LISTING: 'stop_gamepad_listing',
+  LIST_CUSTOM_MODELS: 'list_custom_models',
+  DELETE_CUSTOM_MODEL: 'delete_custom_model',
+  DUPLICATE_CUSTOM_MODEL: 'duplicate_custom_model',
+  RENAME_CUSTOM_MODEL: 'rename_custom_model',
+  COLLECT_CUSTOM_MODELS: 'collect_custom_models',
+  START_MODEL_WATCHING: 'start_model_watching',
+  STOP_MODEL_WATCHING: 'stop_model_watching',
+  GENERATE_MODEL_COVER: 'generate_model_cover',
+  PACK_KEY_ATLAS: 'pack_key_atlas',
+  CREATE_CAT_WINDOW: 'create_cat_window',
+  CLOSE_CAT_WINDOW: 'close_cat_window',
+  SET_INPUT_SOURCE: 'set_input_source',
+  START_CONTROL_SERVER: 'start_control_server',
+  STOP_CONTROL_SERVER: 'stop_control_server',
+  SET_OVERLAY_SCENE: 'set_overlay_scene',
+  UPDATE_TRAY: 'update_tray',
+  GET_SETTINGS: 'get_settings',
+  SAVE_SETTINGS: 'save_settings',
+  EXPORT_BACKUP: 'export_backup',
+  INSPECT_BACKUP: 'inspect_backup',
+  RESTORE_BACKUP: 'restore_backup',
+  SET_IDLE_THRESHOLD: 'set_idle_threshold',
+  GET_IDLE_DURATION: 'get_idle_duration',
+  RUN_PENDING_COMMAND: 'run_pending_command',
 }
 
 export const LANGUAGE = {
@@ -31,3 +62,5 @@ export const WINDOW_LABEL = {
   MAIN: 'main',
   PREFERENCE: 'preference',
 } as const
+
+export const CAT_WINDOW_PREFIX = 'cat-'
@@ -0,0 +1,7 @@
+import { createApp } from 'vue'
+
+import App from './App.vue'
+
+import 'virtual:uno.css'
+
+createApp(App).mount('#app')
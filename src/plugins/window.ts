@@ -2,24 +2,63 @@ import { invoke } from '@tauri-apps/api/core'
 import { emit } from '@tauri-apps/api/event'
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
 
-import type { WINDOW_LABEL } from '../constants'
-
-import { LISTEN_KEY } from '../constants'
+import { CAT_WINDOW_PREFIX, LISTEN_KEY, WINDOW_LABEL } from '../constants'
 
 export type WindowLabel = typeof WINDOW_LABEL[keyof typeof WINDOW_LABEL]
 
+export type FollowSource = 'cursor' | 'focusedWindow'
+
+export type FullscreenAction = 'hide' | 'lower'
+
+export type OverlayLayer = 'overlay' | 'bottom'
+
+export interface OverlayConfig {
+  layer: OverlayLayer
+  anchor: DockAnchor
+  margin: number
+}
+
+export interface InputMask {
+  width: number
+  height: number
+  alpha: number[]
+}
+
+export interface WindowState {
+  label: string
+  visible: boolean
+  alwaysOnTop: boolean
+  alwaysOnBottom: boolean
+  taskbarVisible: boolean | null
+  clickThrough: boolean
+  desktopWidget: boolean
+}
+
+export type DockAnchor = 'topLeft' | 'top' | 'topRight' | 'left' | 'right' | 'bottomLeft' | 'bottom' | 'bottomRight'
+
 const COMMAND = {
   SHOW_WINDOW: 'plugin:custom-window|show_window',
   HIDE_WINDOW: 'plugin:custom-window|hide_window',
   SET_ALWAYS_ON_TOP: 'plugin:custom-window|set_always_on_top',
   SET_TASKBAR_VISIBILITY: 'plugin:custom-window|set_taskbar_visibility',
+  SNAP_WINDOW: 'plugin:custom-window|snap_window',
+  DOCK_WINDOW: 'plugin:custom-window|dock_window',
+  SET_FOLLOW_MONITOR: 'plugin:custom-window|set_follow_monitor',
+  RESTORE_WINDOW_POSITION: 'plugin:custom-window|restore_window_position',
+  SET_INPUT_SHAPE: 'plugin:custom-window|set_input_shape',
+  SET_FULLSCREEN_WATCH: 'plugin:custom-window|set_fullscreen_watch',
+  SET_CLICK_THROUGH: 'plugin:custom-window|set_click_through',
+  GET_WINDOW_STATE: 'plugin:custom-window|get_window_state',
+  SET_OVERLAY_MODE: 'plugin:custom-window|set_overlay_mode',
+  SET_DESKTOP_WIDGET: 'plugin:custom-window|set_desktop_widget',
+  SET_FOCUS_WATCH: 'plugin:custom-window|set_focus_watch',
 }
 
 export function showWindow(label?: WindowLabel) {
   if (label) {
     emit(LISTEN_KEY.SHOW_WINDOW, label)
   } else {
-    invoke(COMMAND.SHOW_WINDOW)
+    return invoke(COMMAND.SHOW_WINDOW)
   }
 }
 
@@ -27,12 +66,16 @@ export function hideWindow(label?: WindowLabel) {
   if (label) {
     emit(LISTEN_KEY.HIDE_WINDOW, label)
   } else {
-    invoke(COMMAND.HIDE_WINDOW)
+    return invoke(COMMAND.HIDE_WINDOW)
   }
 }
 
 export function setAlwaysOnTop(alwaysOnTop: boolean) {
-  invoke(COMMAND.SET_ALWAYS_ON_TOP, { alwaysOnTop })
+  return invoke(COMMAND.SET_ALWAYS_ON_TOP, { alwaysOnTop })
+}
+
+export function isCatWindow(label: string) {
+  return label === WINDOW_LABEL.MAIN || label.startsWith(CAT_WINDOW_PREFIX)
 }
 
 export async function toggleWindowVisible(label?: WindowLabel) {
@@ -50,5 +93,49 @@ export async function toggleWindowVisible(label?: WindowLabel) {
 }
 
 export async function setTaskbarVisibility(visible: boolean) {
-  invoke(COMMAND.SET_TASKBAR_VISIBILITY, { visible })
+  return invoke(COMMAND.SET_TASKBAR_VISIBILITY, { visible })
+}
+
+export function snapWindow(snapDistance: number, keepInScreen: boolean) {
+  return invoke<DockAnchor | null>(COMMAND.SNAP_WINDOW, { snapDistance, keepInScreen })
+}
+
+export function dockWindow(anchor: DockAnchor | null) {
+  return invoke(COMMAND.DOCK_WINDOW, { anchor })
+}
+
+export function setFollowMonitor(enabled: boolean, source: FollowSource, delay: number) {
+  return invoke(COMMAND.SET_FOLLOW_MONITOR, { enabled, source, delay })
+}
+
+export function restoreWindowPosition() {
+  return invoke<boolean>(COMMAND.RESTORE_WINDOW_POSITION)
+}
+
+export function setInputShape(mask: InputMask | null) {
+  return invoke(COMMAND.SET_INPUT_SHAPE, { mask })
+}
+
+export function setFullscreenWatch(enabled: boolean, action: FullscreenAction, allowlist: string[]) {
+  return invoke(COMMAND.SET_FULLSCREEN_WATCH, { enabled, action, allowlist })
+}
+
+export function setClickThrough(enabled: boolean) {
+  return invoke(COMMAND.SET_CLICK_THROUGH, { enabled })
+}
+
+export function getWindowState(label: string) {
+  return invoke<WindowState>(COMMAND.GET_WINDOW_STATE, { label })
+}
+
+export function setOverlayMode(config: OverlayConfig | null) {
+  return invoke<boolean>(COMMAND.SET_OVERLAY_MODE, { config })
+}
+
+export function setDesktopWidget(enabled: boolean) {
+  return invoke<boolean>(COMMAND.SET_DESKTOP_WIDGET, { enabled })
+}
+
+export function setFocusWatch(enabled: boolean) {
+  return invoke(COMMAND.SET_FOCUS_WATCH, { enabled })
 }
@@ -1,5 +1,24 @@
 import { defineStore } from 'pinia'
-import { reactive, ref } from 'vue'
+import { computed, reactive, ref } from 'vue'
+
+import type { DockAnchor, FollowSource, FullscreenAction, OverlayLayer } from '@/plugins/window'
+
+export type InputSource = 'all' | 'keyboard' | 'mouse' | 'gamepad'
+
+export interface CatInstance {
+  modelId?: string
+  inputSource: InputSource
+}
+
+export type ProfileToggle = 'inherit' | 'on' | 'off'
+
+export interface AppProfile {
+  id: string
+  wmClass: string
+  modelId?: string
+  mirror: ProfileToggle
+  visible: ProfileToggle
+}
 
 export interface CatStore {
   model: {
@@ -10,44 +29,40 @@ export interface CatStore {
     autoReleaseDelay: number
     maxFPS: number
     ignoreMouse: boolean
+    hotReload: boolean
+    idleTimeout: number
   }
   window: {
     visible: boolean
     passThrough: boolean
+    transparentPassThrough: boolean
     alwaysOnTop: boolean
+    desktopWidget: boolean
     scale: number
     opacity: number
     radius: number
     hideOnHover: boolean
     hideOnHoverDelay: number
     keepInScreen: boolean
+    snapDistance: number
+    followMonitor: boolean
+    followSource: FollowSource
+    followDelay: number
+    hideOnFullscreen: boolean
+    fullscreenAction: FullscreenAction
+    fullscreenAllowlist: string[]
+    overlayMode: boolean
+    overlayLayer: OverlayLayer
+    overlayAnchor: DockAnchor
+    overlayMargin: number
+  }
+  profile: {
+    enabled: boolean
+    items: AppProfile[]
   }
 }
 
 export const useCatStore = defineStore('cat', () => {
-  /* ------------ 废弃字段（后续删除） ------------ */
-
-  /** @deprecated 请使用 `model.mirror` */
-  const mirrorMode = ref(false)
-
-  /** @deprecated 请使用 `model.mouseMirror` */
-  const mouseMirror = ref(false)
-
-  /** @deprecated 请使用 `window.passThrough` */
-  const penetrable = ref(false)
-
-  /** @deprecated 请使用 `window.alwaysOnTop` */
-  const alwaysOnTop = ref(true)
-
-  /** @deprecated 请使用 `window.scale` */
-  const scale = ref(100)
-
-  /** @deprecated 请使用 `window.opacity` */
-  const opacity = ref(100)
-
-  /** @deprecated 用于标识数据是否已迁移，后续版本将删除 */
-  const migrated = ref(false)
-
   const model = reactive<CatStore['model']>({
     mirror: false,
     mouseMirror: false,
@@ -56,39 +71,66 @@ export const useCatStore = defineStore('cat', () => {
     autoReleaseDelay: 3,
     maxFPS: 60,
     ignoreMouse: false,
+    hotReload: false,
+    idleTimeout: 300,
   })
 
   const window = reactive<CatStore['window']>({
     visible: true,
     passThrough: false,
+    transparentPassThrough: false,
     alwaysOnTop: false,
+    desktopWidget: false,
     scale: 100,
     opacity: 100,
     radius: 0,
     hideOnHover: false,
     hideOnHoverDelay: 0,
     keepInScreen: true,
+    snapDistance: 0,
+    followMonitor: false,
+    followSource: 'cursor',
+    followDelay: 1,
+    hideOnFullscreen: false,
+    fullscreenAction: 'hide',
+    fullscreenAllowlist: [],
+    overlayMode: false,
+    overlayLayer: 'overlay',
+    overlayAnchor: 'bottomRight',
+    overlayMargin: 0,
+  })
+
+  const profile = reactive<CatStore['profile']>({
+    enabled: false,
+    items: [],
   })
 
-  const init = () => {
-    if (migrated.value) return
+  const instances = reactive<Record<string, CatInstance>>({})
 
-    model.mirror = mirrorMode.value
-    model.mouseMirror = mouseMirror.value
+  // Profile matching the focused application, kept out of the saved settings
+  const activeProfile = ref<AppProfile>()
 
-    window.visible = true
-    window.passThrough = penetrable.value
-    window.alwaysOnTop = alwaysOnTop.value
-    window.scale = scale.value
-    window.opacity = opacity.value
+  const resolveToggle = (toggle: ProfileToggle | undefined, fallback: boolean) => {
+    if (!toggle || toggle === 'inherit') return fallback
 
-    migrated.value = true
+    return toggle === 'on'
   }
 
+  const mirrored = computed(() => resolveToggle(activeProfile.value?.mirror, model.mirror))
+
+  const visible = computed(() => resolveToggle(activeProfile.value?.visible, window.visible))
+
   return {
-    migrated,
     model,
     window,
-    init,
+    profile,
+    instances,
+    activeProfile,
+    mirrored,
+    visible,
   }
+}, {
+  tauri: {
+    filterKeys: ['activeProfile'],
+  },
 })
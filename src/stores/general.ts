@@ -1,6 +1,6 @@
 import { defineStore } from 'pinia'
 import { getLocale } from 'tauri-plugin-locale-api'
-import { reactive, ref } from 'vue'
+import { reactive } from 'vue'
 
 import { LANGUAGE } from '@/constants'
 
@@ -21,29 +21,18 @@ export interface GeneralStore {
   update: {
     autoCheck: boolean
   }
+  server: {
+    enabled: boolean
+    port: number
+    token: string
+    overlay: boolean
+  }
+  privacy: {
+    enabled: boolean
+  }
 }
 
 export const useGeneralStore = defineStore('general', () => {
-  /* ------------ 废弃字段（后续删除） ------------ */
-
-  /** @deprecated 请使用 `update.autoCheck` */
-  const autoCheckUpdate = ref(false)
-
-  /** @deprecated 请使用 `app.autostart` */
-  const autostart = ref(false)
-
-  /** @deprecated 请使用 `app.taskbarVisible` */
-  const taskbarVisibility = ref(false)
-
-  /** @deprecated 请使用 `appearance.theme` */
-  const theme = ref<'auto' | Theme>('auto')
-
-  /** @deprecated 请使用 `appearance.isDark` */
-  const isDark = ref(false)
-
-  /** @deprecated 用于标识数据是否已迁移，后续版本将删除 */
-  const migrated = ref(false)
-
   const app = reactive<GeneralStore['app']>({
     autostart: false,
     taskbarVisible: false,
@@ -59,6 +48,17 @@ export const useGeneralStore = defineStore('general', () => {
     autoCheck: false,
   })
 
+  const server = reactive<GeneralStore['server']>({
+    enabled: false,
+    port: 23302,
+    token: '',
+    overlay: false,
+  })
+
+  const privacy = reactive<GeneralStore['privacy']>({
+    enabled: false,
+  })
+
   const getLanguage = async () => {
     const locale = await getLocale<Language>()
 
@@ -71,25 +71,14 @@ export const useGeneralStore = defineStore('general', () => {
 
   const init = async () => {
     appearance.language ??= await getLanguage()
-
-    if (migrated.value) return
-
-    app.autostart = autostart.value
-    app.taskbarVisible = taskbarVisibility.value
-
-    appearance.theme = theme.value
-    appearance.isDark = isDark.value
-
-    update.autoCheck = autoCheckUpdate.value
-
-    migrated.value = true
   }
 
   return {
-    migrated,
     app,
     appearance,
     update,
+    server,
+    privacy,
     init,
   }
 })
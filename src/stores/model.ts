@@ -12,6 +12,7 @@ export type ModelMode = 'standard' | 'keyboard' | 'gamepad'
 
 export interface Model {
   id: string
+  name?: string
   path: string
   mode: ModelMode
   isPreset: boolean
@@ -38,10 +38,6 @@ class Live2d {
   }
 
   public async load(path: string) {
-    await this.initApp()
-
-    this.destroy()
-
     const files = await readDir(path)
 
     const modelFile = files.find(file => file.name.endsWith('.model3.json'))
@@ -52,15 +48,21 @@ class Live2d {
 
     const modelPath = join(path, modelFile.name)
 
-    const modelJSON = JSON5.parse(await readTextFile(modelPath))
+    const content = await readTextFile(modelPath)
+
+    return this.loadModel(content, file => convertFileSrc(join(path, file)))
+  }
+
+  public async loadModel(content: string, resolveFile: (file: string) => string) {
+    await this.initApp()
+
+    this.destroy()
 
     const modelSetting = new CubismSetting({
-      modelJSON,
+      modelJSON: JSON5.parse(content),
     })
 
-    modelSetting.redirectPath(({ file }) => {
-      return convertFileSrc(join(path, file))
-    })
+    modelSetting.redirectPath(({ file }) => resolveFile(file))
 
     this.model = new Live2DSprite({
       modelSetting,
@@ -133,6 +135,14 @@ class Live2d {
   public setMaxFPS(fps: number) {
     Ticker.shared.maxFPS = fps
   }
+
+  public extractCanvas() {
+    if (!this.app || !this.model) return
+
+    const { renderer, stage, screen } = this.app
+
+    return renderer.extract.canvas({ target: stage, frame: screen }) as HTMLCanvasElement
+  }
 }
 
 const live2d = new Live2d()